@@ -6,6 +6,31 @@
 use glam::{Mat4, Quat, Vec2, Vec3};
 use pac_math::Transform;
 
+use crate::frustum::Frustum;
+
+/// Wraps an angle in radians into `[-PI, PI]`, so yaw accumulated over a
+/// long play session doesn't grow large enough for `sin`/`cos` to lose
+/// precision and make rotation jittery
+fn wrap_angle(angle: f32) -> f32 {
+    let tau = std::f32::consts::TAU;
+    angle - tau * (angle / tau).round()
+}
+
+/// Which clip-space convention a `Camera`'s projection matrix targets
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProjectionMode {
+    /// Maps depth to OpenGL's `[-1, 1]` NDC range (`Mat4::perspective_rh_gl`)
+    OpenGl,
+    /// Maps depth to wgpu's `[0, 1]` NDC range (`Mat4::perspective_rh`)
+    #[default]
+    Wgpu,
+    /// wgpu's `[0, 1]` range with near/far swapped so the far plane maps to
+    /// `0` and the near plane to `1`, trading the precision wgpu's default
+    /// wastes on distant geometry for precision near the camera. Requires a
+    /// `Greater` depth compare function and a depth buffer cleared to `0.0`.
+    ReverseZWgpu,
+}
+
 /// A camera with perspective projection and transform
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Camera {
@@ -19,6 +44,12 @@ pub struct Camera {
     pub far: f32,
     /// Aspect ratio (width / height)
     pub aspect_ratio: f32,
+    /// Clip-space convention used by `projection_matrix()`
+    pub projection_mode: ProjectionMode,
+    /// FOV that `update_fov` smoothly eases `fov_y` toward
+    pub target_fov: f32,
+    /// Speed of the exponential FOV approach, in 1/seconds
+    pub fov_transition_speed: f32,
 }
 
 impl Camera {
@@ -30,6 +61,8 @@ impl Camera {
     pub const DEFAULT_FAR: f32 = 1000.0;
     /// Default aspect ratio
     pub const DEFAULT_ASPECT: f32 = 16.0 / 9.0;
+    /// Default speed of the exponential FOV approach, in 1/seconds
+    pub const DEFAULT_FOV_TRANSITION_SPEED: f32 = 8.0;
 
     /// Creates a new camera with default settings
     pub fn new() -> Self {
@@ -39,6 +72,9 @@ impl Camera {
             near: Self::DEFAULT_NEAR,
             far: Self::DEFAULT_FAR,
             aspect_ratio: Self::DEFAULT_ASPECT,
+            projection_mode: ProjectionMode::default(),
+            target_fov: Self::DEFAULT_FOV,
+            fov_transition_speed: Self::DEFAULT_FOV_TRANSITION_SPEED,
         }
     }
 
@@ -50,9 +86,18 @@ impl Camera {
         }
     }
 
-    /// Sets the field of view (in radians)
+    /// Sets the field of view (in radians), instantly. Also resets
+    /// `target_fov` so a subsequent `update_fov` doesn't ease back toward a
+    /// stale target
     pub fn with_fov(mut self, fov_y: f32) -> Self {
         self.fov_y = fov_y;
+        self.target_fov = fov_y;
+        self
+    }
+
+    /// Sets the speed of the exponential approach used by `update_fov`
+    pub fn with_fov_transition_speed(mut self, fov_transition_speed: f32) -> Self {
+        self.fov_transition_speed = fov_transition_speed;
         self
     }
 
@@ -69,9 +114,26 @@ impl Camera {
         self
     }
 
-    /// Returns the perspective projection matrix
+    /// Sets the clip-space convention used by `projection_matrix()`
+    pub fn with_projection_mode(mut self, projection_mode: ProjectionMode) -> Self {
+        self.projection_mode = projection_mode;
+        self
+    }
+
+    /// Returns the perspective projection matrix, in the clip-space
+    /// convention selected by `projection_mode`
     pub fn projection_matrix(&self) -> Mat4 {
-        Mat4::perspective_rh_gl(self.fov_y, self.aspect_ratio, self.near, self.far)
+        match self.projection_mode {
+            ProjectionMode::OpenGl => {
+                Mat4::perspective_rh_gl(self.fov_y, self.aspect_ratio, self.near, self.far)
+            }
+            ProjectionMode::Wgpu => {
+                Mat4::perspective_rh(self.fov_y, self.aspect_ratio, self.near, self.far)
+            }
+            ProjectionMode::ReverseZWgpu => {
+                Mat4::perspective_rh(self.fov_y, self.aspect_ratio, self.far, self.near)
+            }
+        }
     }
 
     /// Returns the view matrix (inverse of transform's model matrix)
@@ -92,6 +154,12 @@ impl Camera {
         self.projection_matrix() * self.view_matrix()
     }
 
+    /// Extracts this camera's view frustum, for culling off-screen geometry
+    /// before submitting draw calls
+    pub fn frustum(&self) -> Frustum {
+        Frustum::from_view_projection(self.view_projection_matrix(), self.projection_mode)
+    }
+
     /// Returns the camera's forward direction
     pub fn forward(&self) -> Vec3 {
         self.transform.forward()
@@ -121,6 +189,20 @@ impl Camera {
     pub fn look_at(&mut self, target: Vec3, up: Vec3) {
         self.transform.look_at(target, up);
     }
+
+    /// Sets the FOV that `update_fov` smoothly eases `fov_y` toward, for
+    /// effects like speed-based FOV widening, aim-down-sights zoom, or warp
+    pub fn set_target_fov(&mut self, target_fov: f32) {
+        self.target_fov = target_fov;
+    }
+
+    /// Eases `fov_y` toward `target_fov` over `delta_time`, using an
+    /// exponential approach scaled by `fov_transition_speed` so the
+    /// transition looks the same regardless of frame rate
+    pub fn update_fov(&mut self, delta_time: f32) {
+        let t = 1.0 - (-self.fov_transition_speed * delta_time).exp();
+        self.fov_y += (self.target_fov - self.fov_y) * t;
+    }
 }
 
 impl Default for Camera {
@@ -241,7 +323,7 @@ impl OrbitCamera {
 
     /// Rotates the camera by delta yaw and pitch
     pub fn rotate(&mut self, delta_yaw: f32, delta_pitch: f32) {
-        self.yaw += delta_yaw * self.rotation_sensitivity;
+        self.yaw = wrap_angle(self.yaw + delta_yaw * self.rotation_sensitivity);
         self.pitch += delta_pitch * self.rotation_sensitivity;
         self.pitch = self.pitch.clamp(self.min_pitch, self.max_pitch);
         self.update_position();
@@ -298,6 +380,12 @@ pub struct FlyCamera {
     pub min_pitch: f32,
     /// Maximum pitch angle
     pub max_pitch: f32,
+    /// Current velocity for the inertial movement mode driven by `update`
+    pub velocity: Vec3,
+    /// Thrust acceleration magnitude applied along the input direction
+    pub thrust: f32,
+    /// Half-life (in seconds) of the exponential velocity damping
+    pub damping_half_life: f32,
 }
 
 impl FlyCamera {
@@ -309,6 +397,10 @@ impl FlyCamera {
     pub const DEFAULT_MIN_PITCH: f32 = -1.55;
     /// Default maximum pitch (in radians, 89 degrees)
     pub const DEFAULT_MAX_PITCH: f32 = 1.55;
+    /// Default thrust acceleration
+    pub const DEFAULT_THRUST: f32 = 20.0;
+    /// Default damping half-life, in seconds
+    pub const DEFAULT_DAMPING_HALF_LIFE: f32 = 0.15;
 
     /// Creates a new fly camera
     pub fn new() -> Self {
@@ -320,9 +412,24 @@ impl FlyCamera {
             pitch: 0.0,
             min_pitch: Self::DEFAULT_MIN_PITCH,
             max_pitch: Self::DEFAULT_MAX_PITCH,
+            velocity: Vec3::ZERO,
+            thrust: Self::DEFAULT_THRUST,
+            damping_half_life: Self::DEFAULT_DAMPING_HALF_LIFE,
         }
     }
 
+    /// Sets the thrust acceleration used by the inertial `update` movement mode
+    pub fn with_thrust(mut self, thrust: f32) -> Self {
+        self.thrust = thrust;
+        self
+    }
+
+    /// Sets the velocity damping half-life used by the inertial `update` movement mode
+    pub fn with_damping_half_life(mut self, half_life: f32) -> Self {
+        self.damping_half_life = half_life;
+        self
+    }
+
     /// Sets the movement speed
     pub fn with_speed(mut self, speed: f32) -> Self {
         self.speed = speed;
@@ -344,7 +451,7 @@ impl FlyCamera {
 
     /// Updates camera rotation from mouse movement
     pub fn rotate_from_mouse(&mut self, delta: Vec2) {
-        self.yaw += delta.x * self.sensitivity;
+        self.yaw = wrap_angle(self.yaw + delta.x * self.sensitivity);
         self.pitch += -delta.y * self.sensitivity;
         self.pitch = self.pitch.clamp(self.min_pitch, self.max_pitch);
         self.update_rotation();
@@ -400,6 +507,24 @@ impl FlyCamera {
         let movement = (forward * forward_back + right * right_left).normalize_or_zero();
         self.camera.transform.position += movement * self.speed * delta_time;
     }
+
+    /// Integrates smooth, inertial movement for one frame from WASD-style
+    /// input, each in `-1.0..=1.0`. Unlike `move_wasd`, this accelerates the
+    /// camera via `thrust` and decays `velocity` over `damping_half_life`
+    /// rather than snapping to a constant speed, for cinematic free-flight.
+    pub fn update(&mut self, forward_back: f32, right_left: f32, up_down: f32, delta_time: f32) {
+        let forward = self.camera.forward();
+        let right = self.camera.right();
+
+        let direction = (forward * forward_back + right * right_left + Vec3::Y * up_down)
+            .normalize_or_zero();
+        self.velocity += direction * self.thrust * delta_time;
+
+        let damping = 0.5_f32.powf(delta_time / self.damping_half_life);
+        self.velocity *= damping;
+
+        self.camera.transform.position += self.velocity * delta_time;
+    }
 }
 
 impl Default for FlyCamera {
@@ -408,6 +533,101 @@ impl Default for FlyCamera {
     }
 }
 
+/// Third-person follow camera controller - trails a moving target from
+/// behind at a fixed offset, using a critically-damped spring to smooth
+/// out sudden target movement
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FollowCamera {
+    /// The camera being controlled
+    pub camera: Camera,
+    /// The most recent target position passed to `update`
+    pub target_position: Vec3,
+    /// Yaw the target is facing; the camera follows from behind this direction
+    pub target_yaw: f32,
+    /// Desired distance behind the target
+    pub distance_back: f32,
+    /// Desired height above the target
+    pub height_up: f32,
+    /// Current spring velocity
+    pub velocity: Vec3,
+    /// Approximate time (in seconds) the camera takes to settle near the target
+    pub smoothing_time: f32,
+}
+
+impl FollowCamera {
+    /// Default distance behind the target
+    pub const DEFAULT_DISTANCE_BACK: f32 = 5.0;
+    /// Default height above the target
+    pub const DEFAULT_HEIGHT_UP: f32 = 2.0;
+    /// Default spring smoothing time, in seconds
+    pub const DEFAULT_SMOOTHING_TIME: f32 = 0.2;
+
+    /// Creates a new follow camera
+    pub fn new() -> Self {
+        Self {
+            camera: Camera::new(),
+            target_position: Vec3::ZERO,
+            target_yaw: 0.0,
+            distance_back: Self::DEFAULT_DISTANCE_BACK,
+            height_up: Self::DEFAULT_HEIGHT_UP,
+            velocity: Vec3::ZERO,
+            smoothing_time: Self::DEFAULT_SMOOTHING_TIME,
+        }
+    }
+
+    /// Sets the desired distance behind and height above the target
+    pub fn with_offset(mut self, distance_back: f32, height_up: f32) -> Self {
+        self.distance_back = distance_back;
+        self.height_up = height_up;
+        self
+    }
+
+    /// Sets the spring smoothing time
+    pub fn with_smoothing_time(mut self, smoothing_time: f32) -> Self {
+        self.smoothing_time = smoothing_time;
+        self
+    }
+
+    /// Sets the yaw the target is currently facing, used to position the
+    /// camera behind it
+    pub fn set_target_yaw(&mut self, yaw: f32) {
+        self.target_yaw = yaw;
+    }
+
+    /// The point the camera wants to be at this frame: `distance_back`
+    /// behind and `height_up` above the target, given `target_yaw`
+    fn desired_position(&self) -> Vec3 {
+        let forward = Quat::from_rotation_y(self.target_yaw) * Vec3::NEG_Z;
+        self.target_position - forward * self.distance_back + Vec3::Y * self.height_up
+    }
+
+    /// Advances the spring toward the desired follow position and re-aims
+    /// the camera at the target. Uses a semi-implicit critically-damped
+    /// spring (`omega = 2 / smoothing_time`) that stays stable for large `dt`.
+    pub fn update(&mut self, target: Vec3, delta_time: f32) {
+        self.target_position = target;
+        let desired = self.desired_position();
+
+        let omega = 2.0 / self.smoothing_time.max(1e-4);
+        let x = omega * delta_time;
+        let exp_decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+        let current = self.camera.transform.position;
+        let displacement = current - desired;
+        let temp = (self.velocity + displacement * omega) * delta_time;
+        self.velocity = (self.velocity - temp * omega) * exp_decay;
+        self.camera.transform.position = desired + (displacement + temp) * exp_decay;
+
+        self.camera.look_at(self.target_position, Vec3::Y);
+    }
+}
+
+impl Default for FollowCamera {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,6 +719,37 @@ mod tests {
         assert!(fly.pitch >= fly.min_pitch);
     }
 
+    #[test]
+    fn test_camera_default_projection_mode_is_wgpu() {
+        let camera = Camera::new();
+        assert_eq!(camera.projection_mode, ProjectionMode::Wgpu);
+    }
+
+    #[test]
+    fn test_reverse_z_maps_near_and_far_oppositely_to_wgpu() {
+        let camera = Camera::new().with_projection_mode(ProjectionMode::Wgpu);
+        let reverse_z = camera
+            .with_projection_mode(ProjectionMode::ReverseZWgpu)
+            .projection_matrix();
+        let wgpu = camera.projection_matrix();
+
+        let near_point = Vec3::new(0.0, 0.0, -camera.near);
+        let wgpu_near_depth = (wgpu * near_point.extend(1.0)).z / (wgpu * near_point.extend(1.0)).w;
+        let reverse_near_depth =
+            (reverse_z * near_point.extend(1.0)).z / (reverse_z * near_point.extend(1.0)).w;
+
+        assert!(wgpu_near_depth < 0.1);
+        assert!(reverse_near_depth > 0.9);
+
+        let far_point = Vec3::new(0.0, 0.0, -camera.far);
+        let wgpu_far_depth = (wgpu * far_point.extend(1.0)).z / (wgpu * far_point.extend(1.0)).w;
+        let reverse_far_depth =
+            (reverse_z * far_point.extend(1.0)).z / (reverse_z * far_point.extend(1.0)).w;
+
+        assert!(wgpu_far_depth > 0.9);
+        assert!(reverse_far_depth < 0.1);
+    }
+
     #[test]
     fn test_camera_builder_pattern() {
         let camera = Camera::new()
@@ -528,6 +779,102 @@ mod tests {
         assert_eq!(orbit.max_pitch, 1.0);
     }
 
+    #[test]
+    fn test_fly_camera_inertial_update_accelerates_and_damps() {
+        let mut fly = FlyCamera::new().with_thrust(10.0).with_damping_half_life(0.1);
+
+        fly.update(1.0, 0.0, 0.0, 1.0 / 60.0);
+        assert!(fly.velocity.length() > 0.0);
+        let first_velocity = fly.velocity.length();
+
+        // With no further input, damping should shrink the velocity each frame
+        for _ in 0..10 {
+            fly.update(0.0, 0.0, 0.0, 1.0 / 60.0);
+        }
+        assert!(fly.velocity.length() < first_velocity);
+    }
+
+    #[test]
+    fn test_follow_camera_converges_to_desired_offset() {
+        let mut follow = FollowCamera::new().with_offset(5.0, 2.0);
+        let target = Vec3::new(0.0, 0.0, 10.0);
+        // forward at target_yaw == 0.0 is -Z, so "behind" the target is +Z
+        let desired = target + Vec3::new(0.0, 2.0, 5.0);
+
+        let initial_distance = (follow.camera.transform.position - desired).length();
+
+        for _ in 0..120 {
+            follow.update(target, 1.0 / 60.0);
+        }
+
+        let final_distance = (follow.camera.transform.position - desired).length();
+        assert!(final_distance < initial_distance);
+        assert!(final_distance < 0.01);
+    }
+
+    #[test]
+    fn test_follow_camera_looks_at_target() {
+        let mut follow = FollowCamera::new();
+        follow.update(Vec3::new(3.0, 0.0, 0.0), 1.0 / 60.0);
+
+        let forward = follow.camera.forward();
+        let to_target = (Vec3::new(3.0, 0.0, 0.0) - follow.camera.transform.position).normalize();
+        assert!(forward.dot(to_target) > 0.9);
+    }
+
+    #[test]
+    fn test_orbit_camera_yaw_wraps_to_stay_bounded() {
+        let mut orbit = OrbitCamera::new();
+        orbit.rotation_sensitivity = 1.0;
+
+        for _ in 0..1000 {
+            orbit.rotate(std::f32::consts::PI, 0.0);
+        }
+
+        assert!(orbit.yaw.abs() <= std::f32::consts::PI);
+    }
+
+    #[test]
+    fn test_fly_camera_yaw_wraps_to_stay_bounded() {
+        let mut fly = FlyCamera::new();
+        fly.sensitivity = 1.0;
+
+        for _ in 0..1000 {
+            fly.rotate_from_mouse(Vec2::new(std::f32::consts::PI, 0.0));
+        }
+
+        assert!(fly.yaw.abs() <= std::f32::consts::PI);
+    }
+
+    #[test]
+    fn test_camera_update_fov_eases_toward_target() {
+        let mut camera = Camera::new().with_fov(std::f32::consts::FRAC_PI_4);
+        camera.set_target_fov(std::f32::consts::FRAC_PI_2);
+
+        let initial_gap = camera.target_fov - camera.fov_y;
+        camera.update_fov(1.0 / 60.0);
+        let remaining_gap = camera.target_fov - camera.fov_y;
+
+        assert!(remaining_gap > 0.0);
+        assert!(remaining_gap < initial_gap);
+
+        for _ in 0..300 {
+            camera.update_fov(1.0 / 60.0);
+        }
+        assert!((camera.fov_y - camera.target_fov).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_camera_with_fov_resets_target() {
+        let mut camera = Camera::new();
+        camera.set_target_fov(std::f32::consts::FRAC_PI_2);
+
+        let camera = camera.with_fov(std::f32::consts::FRAC_PI_4);
+
+        assert_eq!(camera.fov_y, std::f32::consts::FRAC_PI_4);
+        assert_eq!(camera.target_fov, std::f32::consts::FRAC_PI_4);
+    }
+
     #[test]
     fn test_fly_camera_builder() {
         let fly = FlyCamera::new()
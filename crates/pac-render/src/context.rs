@@ -1,25 +1,49 @@
 //! GPU context management for wgpu rendering
 
-use wgpu::{Instance, Surface, Device, Queue, SurfaceConfiguration};
+use wgpu::{Device, Instance, Queue, Surface, SurfaceConfiguration};
 use std::sync::Arc;
 
+/// An owned color target used in place of a swapchain when there is no
+/// window to present to (thumbnail generation, server-side rendering,
+/// screenshot/test pipelines)
+pub struct OffscreenTarget {
+    /// The render target texture, created with `RENDER_ATTACHMENT | COPY_SRC`
+    /// so it can both be drawn into and copied out to a CPU buffer
+    pub texture: wgpu::Texture,
+    /// View over `texture` for use as a render attachment
+    pub view: wgpu::TextureView,
+    /// Width of the target in pixels
+    pub width: u32,
+    /// Height of the target in pixels
+    pub height: u32,
+    /// Pixel format of the target
+    pub format: wgpu::TextureFormat,
+}
+
 /// GPU context containing all necessary wgpu handles for rendering
+///
+/// Either `surface`/`config` or `offscreen` is populated, depending on
+/// whether the context was created via `new` (presentable, windowed) or
+/// `new_headless` (offscreen); device/queue setup is shared by both paths.
 pub struct RenderContext {
     /// WGPU instance for GPU enumeration
     pub instance: Instance,
-    /// Surface for presenting rendered frames
-    pub surface: Surface<'static>,
+    /// Surface for presenting rendered frames, absent for headless contexts
+    pub surface: Option<Surface<'static>>,
     /// GPU device for command submission
     pub device: Device,
     /// Command queue for submitting work to the GPU
     pub queue: Queue,
-    /// Surface configuration (format, size, present mode)
-    pub config: SurfaceConfiguration,
+    /// Surface configuration (format, size, present mode), absent for
+    /// headless contexts
+    pub config: Option<SurfaceConfiguration>,
+    /// Owned color target used instead of a surface for headless contexts
+    pub offscreen: Option<OffscreenTarget>,
 }
 
 impl RenderContext {
     /// Create a new render context with the given window
-    /// 
+    ///
     /// # Arguments
     /// * `window` - The window to create a surface for
     pub async fn new(window: Arc<winit::window::Window>) -> Self {
@@ -66,7 +90,82 @@ impl RenderContext {
         };
 
         // Request device and queue
-        let (device, queue) = adapter
+        let (device, queue) = Self::request_device(&adapter).await;
+
+        // Configure the surface
+        surface.configure(&device, &config);
+
+        Self {
+            instance,
+            surface: Some(surface),
+            device,
+            queue,
+            config: Some(config),
+            offscreen: None,
+        }
+    }
+
+    /// Create a headless render context with no window or presentable
+    /// surface, rendering into an owned `width x height` texture of
+    /// `format` instead of a swapchain
+    ///
+    /// # Arguments
+    /// * `width` - Width of the offscreen target in pixels
+    /// * `height` - Height of the offscreen target in pixels
+    /// * `format` - Pixel format of the offscreen target
+    pub async fn new_headless(width: u32, height: u32, format: wgpu::TextureFormat) -> Self {
+        let instance = Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            ..Default::default()
+        });
+
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::default(),
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await
+            .expect("Failed to find an appropriate adapter");
+
+        let (device, queue) = Self::request_device(&adapter).await;
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen Render Target"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self {
+            instance,
+            surface: None,
+            device,
+            queue,
+            config: None,
+            offscreen: Some(OffscreenTarget {
+                texture,
+                view,
+                width,
+                height,
+                format,
+            }),
+        }
+    }
+
+    /// Requests a device and queue from `adapter`, shared by both the
+    /// presentable and headless construction paths
+    async fn request_device(adapter: &wgpu::Adapter) -> (Device, Queue) {
+        adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
                     required_features: wgpu::Features::empty(),
@@ -77,22 +176,12 @@ impl RenderContext {
                 None,
             )
             .await
-            .expect("Failed to create device");
-
-        // Configure the surface
-        surface.configure(&device, &config);
-
-        Self {
-            instance,
-            surface,
-            device,
-            queue,
-            config,
-        }
+            .expect("Failed to create device")
     }
 
-    /// Resize the surface to match the new window size
-    /// 
+    /// Resize the surface to match the new window size. No-op for headless
+    /// contexts, which have a fixed-size offscreen target
+    ///
     /// # Arguments
     /// * `new_width` - New width in pixels
     /// * `new_height` - New height in pixels
@@ -100,14 +189,99 @@ impl RenderContext {
         if new_width == 0 || new_height == 0 {
             return;
         }
-        self.config.width = new_width;
-        self.config.height = new_height;
-        self.surface.configure(&self.device, &self.config);
+        let (Some(surface), Some(config)) = (&self.surface, &mut self.config) else {
+            return;
+        };
+        config.width = new_width;
+        config.height = new_height;
+        surface.configure(&self.device, config);
     }
 
-    /// Get the current surface texture for rendering
+    /// Get the current surface texture for rendering. Panics if this
+    /// context is headless; use `offscreen`'s view as the render
+    /// attachment instead
     pub fn get_current_texture(&self) -> Result<wgpu::SurfaceTexture, wgpu::SurfaceError> {
-        self.surface.get_current_texture()
+        self.surface
+            .as_ref()
+            .expect("get_current_texture called on a headless RenderContext")
+            .get_current_texture()
+    }
+
+    /// Copies the headless color target into a CPU-readable buffer of
+    /// tightly-packed pixel rows and returns its raw bytes. Panics if this
+    /// context is not headless
+    pub async fn read_pixels(&self) -> Vec<u8> {
+        let target = self
+            .offscreen
+            .as_ref()
+            .expect("read_pixels called on a non-headless RenderContext");
+
+        let bytes_per_pixel = target
+            .format
+            .block_copy_size(None)
+            .expect("offscreen format must have a known pixel size") as u32;
+        // Row bytes must be padded to wgpu's buffer-copy alignment
+        let unpadded_bytes_per_row = bytes_per_pixel * target.width;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = unpadded_bytes_per_row.div_ceil(align) * align;
+
+        let buffer_size = (padded_bytes_per_row * target.height) as wgpu::BufferAddress;
+        let output_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Offscreen Readback Buffer"),
+            size: buffer_size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Offscreen Readback Encoder"),
+            });
+        encoder.copy_texture_to_buffer(
+            target.texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &output_buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(target.height),
+                },
+            },
+            wgpu::Extent3d {
+                width: target.width,
+                height: target.height,
+                depth_or_array_layers: 1,
+            },
+        );
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = output_buffer.slice(..);
+        let mapped = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let mapped_handle = mapped.clone();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            *mapped_handle.lock().unwrap() = Some(result);
+        });
+        // wgpu's map_async callback fires during device polling, not on a
+        // separate executor, so block here rather than truly awaiting it
+        loop {
+            self.device.poll(wgpu::Maintain::Wait);
+            if let Some(result) = mapped.lock().unwrap().take() {
+                result.expect("failed to map readback buffer");
+                break;
+            }
+        }
+
+        let padded = slice.get_mapped_range().to_vec();
+        if padded_bytes_per_row == unpadded_bytes_per_row {
+            return padded;
+        }
+
+        let mut pixels = Vec::with_capacity((unpadded_bytes_per_row * target.height) as usize);
+        for row in padded.chunks(padded_bytes_per_row as usize) {
+            pixels.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+        }
+        pixels
     }
 }
 
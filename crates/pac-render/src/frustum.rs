@@ -0,0 +1,173 @@
+//! View frustum extraction and culling tests
+//!
+//! Extracts the six clipping planes of a camera's view frustum from its
+//! combined view-projection matrix using the Gribb-Hartmann method, so
+//! callers can cull off-screen geometry before submitting draw calls.
+
+use glam::{Mat4, Vec3, Vec4};
+
+use crate::camera::ProjectionMode;
+
+/// A clipping plane in the form `dot(normal, p) + d = 0`, with `normal` unit length
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+    pub normal: Vec3,
+    pub d: f32,
+}
+
+impl Plane {
+    /// Builds a normalized plane from an unnormalized row of a projection matrix
+    fn from_row(row: Vec4) -> Self {
+        let normal = Vec3::new(row.x, row.y, row.z);
+        let length = normal.length();
+        Self {
+            normal: normal / length,
+            d: row.w / length,
+        }
+    }
+
+    /// Signed distance from `point` to this plane; negative means behind it
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) + self.d
+    }
+}
+
+/// The six planes bounding a camera's view frustum
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frustum {
+    pub left: Plane,
+    pub right: Plane,
+    pub bottom: Plane,
+    pub top: Plane,
+    pub near: Plane,
+    pub far: Plane,
+}
+
+impl Frustum {
+    /// Extracts the six frustum planes from a combined view-projection
+    /// matrix, given the clip-space convention it was built with. The near
+    /// plane differs between conventions: OpenGL's `[-1, 1]` depth range
+    /// puts it at `row3 + row2`, while wgpu's `[0, 1]` range puts it at
+    /// `row2` alone. `ReverseZWgpu` swaps which end of `[0, 1]` is near, so
+    /// `row2` and `row3 - row2` there give the world far and near planes
+    /// respectively — the reverse of every other mode — and are swapped back
+    /// before being stored, so `Frustum.near`/`Frustum.far` always mean what
+    /// their names say regardless of projection mode.
+    pub fn from_view_projection(view_projection: Mat4, projection_mode: ProjectionMode) -> Self {
+        let row0 = view_projection.row(0);
+        let row1 = view_projection.row(1);
+        let row2 = view_projection.row(2);
+        let row3 = view_projection.row(3);
+
+        let (near, far) = match projection_mode {
+            ProjectionMode::OpenGl => (row3 + row2, row3 - row2),
+            ProjectionMode::Wgpu => (row2, row3 - row2),
+            ProjectionMode::ReverseZWgpu => (row3 - row2, row2),
+        };
+
+        Self {
+            left: Plane::from_row(row3 + row0),
+            right: Plane::from_row(row3 - row0),
+            bottom: Plane::from_row(row3 + row1),
+            top: Plane::from_row(row3 - row1),
+            near: Plane::from_row(near),
+            far: Plane::from_row(far),
+        }
+    }
+
+    /// Returns each plane in turn
+    fn planes(&self) -> [Plane; 6] {
+        [
+            self.left,
+            self.right,
+            self.bottom,
+            self.top,
+            self.near,
+            self.far,
+        ]
+    }
+
+    /// Returns true if a sphere with the given center and radius intersects
+    /// or is inside the frustum
+    pub fn contains_sphere(&self, center: Vec3, radius: f32) -> bool {
+        self.planes()
+            .iter()
+            .all(|plane| plane.distance_to_point(center) >= -radius)
+    }
+
+    /// Returns true if the axis-aligned box `[min, max]` intersects or is
+    /// inside the frustum
+    pub fn contains_aabb(&self, min: Vec3, max: Vec3) -> bool {
+        self.planes().iter().all(|plane| {
+            // The "positive vertex": the AABB corner farthest along the plane normal
+            let positive_vertex = Vec3::new(
+                if plane.normal.x >= 0.0 { max.x } else { min.x },
+                if plane.normal.y >= 0.0 { max.y } else { min.y },
+                if plane.normal.z >= 0.0 { max.z } else { min.z },
+            );
+            plane.distance_to_point(positive_vertex) >= 0.0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Camera;
+
+    #[test]
+    fn test_frustum_contains_origin_sphere() {
+        let camera = Camera::new();
+        let frustum = camera.frustum();
+
+        assert!(frustum.contains_sphere(Vec3::new(0.0, 0.0, -5.0), 1.0));
+    }
+
+    #[test]
+    fn test_frustum_rejects_sphere_behind_camera() {
+        let camera = Camera::new();
+        let frustum = camera.frustum();
+
+        assert!(!frustum.contains_sphere(Vec3::new(0.0, 0.0, 5.0), 0.1));
+    }
+
+    #[test]
+    fn test_frustum_contains_aabb_in_view() {
+        let camera = Camera::new();
+        let frustum = camera.frustum();
+
+        assert!(frustum.contains_aabb(
+            Vec3::new(-0.5, -0.5, -5.5),
+            Vec3::new(0.5, 0.5, -4.5)
+        ));
+    }
+
+    #[test]
+    fn test_near_plane_matches_wgpu_clip_space_for_default_camera() {
+        // Camera::new() defaults to ProjectionMode::Wgpu, whose `[0, 1]`
+        // depth range places the near plane at `row2` alone, not at
+        // `row3 + row2` (the OpenGL `[-1, 1]` formula)
+        let camera = Camera::new();
+        let frustum = camera.frustum();
+        let epsilon = 0.01;
+
+        // Just beyond the near clip plane: should be in front of it
+        let just_inside = Vec3::new(0.0, 0.0, -(Camera::DEFAULT_NEAR + epsilon));
+        assert!(frustum.near.distance_to_point(just_inside) >= 0.0);
+
+        // Just between the camera and the near clip plane: should be culled
+        let just_outside = Vec3::new(0.0, 0.0, -(Camera::DEFAULT_NEAR - epsilon));
+        assert!(frustum.near.distance_to_point(just_outside) < 0.0);
+    }
+
+    #[test]
+    fn test_frustum_rejects_aabb_far_off_to_the_side() {
+        let camera = Camera::new();
+        let frustum = camera.frustum();
+
+        assert!(!frustum.contains_aabb(
+            Vec3::new(1000.0, 1000.0, -5.0),
+            Vec3::new(1001.0, 1001.0, -5.0)
+        ));
+    }
+}
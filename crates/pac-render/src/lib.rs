@@ -3,5 +3,9 @@
 pub use wgpu;
 
 pub mod camera;
+pub mod context;
+pub mod frustum;
 
-pub use camera::{Camera, FlyCamera, OrbitCamera};
+pub use camera::{Camera, FlyCamera, FollowCamera, OrbitCamera, ProjectionMode};
+pub use context::{OffscreenTarget, RenderContext};
+pub use frustum::{Frustum, Plane};
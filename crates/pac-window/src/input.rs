@@ -1,14 +1,123 @@
 //! Input handling for PAC game engine
 //!
-//! Provides keyboard and mouse state tracking with event handling.
+//! Provides keyboard, mouse, and gamepad state tracking with event handling.
+//!
+//! `InputMap` and its binding types derive `Serialize`/`Deserialize`, which
+//! requires `winit`'s `serde` feature (for `KeyCode`/`MouseButton`) and
+//! `gilrs`'s `serde-serialize` feature (for `Button`/`Axis`) to be enabled
+//! in this crate's `Cargo.toml`; without them this module fails to compile.
 
-use std::collections::HashSet;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
 use winit::{
-    event::{ElementState, KeyEvent, MouseButton, WindowEvent},
+    event::{DeviceEvent, ElementState, KeyEvent, MouseButton, WindowEvent},
     keyboard::{KeyCode, PhysicalKey},
 };
 
-/// Tracks the current state of keyboard and mouse input
+/// A double-buffered queue of discrete events of type `T`.
+///
+/// Producers call [`Events::send`] as events occur; consumers read the
+/// events from the *previous* buffer swap via [`Events::iter`] or
+/// [`Events::drain`]. Calling [`Events::update`] (once per frame, alongside
+/// [`InputState::update`]) swaps the buffers so each sent event is visible
+/// to readers for exactly one frame, even if several of the same kind
+/// arrived since the last swap.
+#[derive(Debug, Clone)]
+pub struct Events<T> {
+    readable: VecDeque<T>,
+    writing: VecDeque<T>,
+}
+
+impl<T> Events<T> {
+    /// Creates an empty event queue
+    pub fn new() -> Self {
+        Self {
+            readable: VecDeque::new(),
+            writing: VecDeque::new(),
+        }
+    }
+
+    /// Queues an event; it becomes readable after the next `update()`
+    pub fn send(&mut self, event: T) {
+        self.writing.push_back(event);
+    }
+
+    /// Swaps the buffers: events sent since the last call become readable,
+    /// and the previously readable events are dropped
+    pub fn update(&mut self) {
+        self.readable.clear();
+        std::mem::swap(&mut self.readable, &mut self.writing);
+    }
+
+    /// Iterates this frame's readable events
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.readable.iter()
+    }
+
+    /// Drains this frame's readable events
+    pub fn drain(&mut self) -> std::collections::vec_deque::Drain<'_, T> {
+        self.readable.drain(..)
+    }
+
+    /// Returns true if there are no readable events this frame
+    pub fn is_empty(&self) -> bool {
+        self.readable.is_empty()
+    }
+}
+
+impl<T> Default for Events<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A key was pressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyPressed {
+    pub key: KeyCode,
+}
+
+/// A key was released
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyReleased {
+    pub key: KeyCode,
+}
+
+/// A mouse button changed state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MouseButtonChanged {
+    pub button: MouseButton,
+    pub state: ElementState,
+}
+
+/// Relative mouse motion reported for a single `DeviceEvent::MouseMotion`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MouseMotion {
+    pub delta: (f64, f64),
+}
+
+/// A mouse wheel scroll, normalized to line-delta units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MouseWheel {
+    pub delta: (f32, f32),
+}
+
+/// The cursor entered the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorEntered;
+
+/// The cursor left the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorLeft;
+
+/// Engine-assigned identifier for a connected gamepad.
+///
+/// This is stable for the lifetime of a connection and is distinct from the
+/// raw backend id `gilrs` hands out, which games should not need to see.
+pub type GamepadId = u32;
+
+/// Tracks the current state of keyboard, mouse, and gamepad input
 #[derive(Debug, Clone, Default)]
 pub struct InputState {
     /// Set of currently pressed keys
@@ -31,6 +140,48 @@ pub struct InputState {
     mouse_wheel_delta: (f32, f32),
     /// Cursor is currently within window bounds
     cursor_in_window: bool,
+    /// Maps the raw backend gamepad id to the engine-assigned `GamepadId`
+    gamepad_ids: HashMap<gilrs::GamepadId, GamepadId>,
+    /// Next engine-assigned gamepad id to hand out on connection
+    next_gamepad_id: GamepadId,
+    /// Set of currently pressed `(gamepad, button)` pairs
+    gamepad_buttons_pressed: HashSet<(GamepadId, gilrs::Button)>,
+    /// `(gamepad, button)` pairs pressed this frame
+    gamepad_buttons_just_pressed: HashSet<(GamepadId, gilrs::Button)>,
+    /// `(gamepad, button)` pairs released this frame
+    gamepad_buttons_just_released: HashSet<(GamepadId, gilrs::Button)>,
+    /// Current analog axis values, keyed by `(gamepad, axis)`
+    gamepad_axes: HashMap<(GamepadId, gilrs::Axis), f32>,
+    /// Unbounded relative mouse motion accumulated this frame from
+    /// `DeviceEvent::MouseMotion`, unaffected by cursor clamping at window edges
+    raw_mouse_delta: (f64, f64),
+    /// Total time elapsed across all `update(delta_time)` calls
+    elapsed_time: f32,
+    /// `elapsed_time` as of the previous `update(delta_time)` call
+    previous_elapsed_time: f32,
+    /// Time at which each currently-pressed key entered the pressed state
+    key_press_times: HashMap<KeyCode, f32>,
+    /// Time at which each currently-pressed mouse button entered the pressed state
+    mouse_button_press_times: HashMap<MouseButton, f32>,
+    /// Time and position of the most recent release of each mouse button,
+    /// used to detect multi-clicks
+    last_mouse_release: HashMap<MouseButton, (f32, (f64, f64))>,
+    /// Consecutive click count for each mouse button (2 = double-click, etc.)
+    mouse_click_counts: HashMap<MouseButton, u32>,
+    /// Buffered discrete key-press events
+    key_pressed_events: Events<KeyPressed>,
+    /// Buffered discrete key-release events
+    key_released_events: Events<KeyReleased>,
+    /// Buffered discrete mouse button events
+    mouse_button_events: Events<MouseButtonChanged>,
+    /// Buffered discrete raw mouse motion events
+    mouse_motion_events: Events<MouseMotion>,
+    /// Buffered discrete mouse wheel events
+    mouse_wheel_events: Events<MouseWheel>,
+    /// Buffered discrete cursor-entered events
+    cursor_entered_events: Events<CursorEntered>,
+    /// Buffered discrete cursor-left events
+    cursor_left_events: Events<CursorLeft>,
 }
 
 impl InputState {
@@ -39,15 +190,96 @@ impl InputState {
         Self::default()
     }
 
-    /// Updates the input state at the beginning of each frame
-    /// Clears the "just" states so they only last one frame
-    pub fn update(&mut self) {
+    /// Updates the input state at the beginning of each frame.
+    /// Clears the "just" states so they only last one frame, and advances
+    /// the internal clock used for key/mouse repeat and multi-click timing
+    /// by `delta_time` seconds.
+    pub fn update(&mut self, delta_time: f32) {
         self.keys_just_pressed.clear();
         self.keys_just_released.clear();
         self.mouse_buttons_just_pressed.clear();
         self.mouse_buttons_just_released.clear();
         self.mouse_wheel_delta = (0.0, 0.0);
         self.previous_mouse_position = self.mouse_position;
+        self.gamepad_buttons_just_pressed.clear();
+        self.gamepad_buttons_just_released.clear();
+        self.raw_mouse_delta = (0.0, 0.0);
+        self.key_pressed_events.update();
+        self.key_released_events.update();
+        self.mouse_button_events.update();
+        self.mouse_motion_events.update();
+        self.mouse_wheel_events.update();
+        self.cursor_entered_events.update();
+        self.cursor_left_events.update();
+        self.previous_elapsed_time = self.elapsed_time;
+        self.elapsed_time += delta_time;
+    }
+
+    /// Processes a raw device event, currently used for unbounded relative
+    /// mouse motion that isn't clamped by the window like `CursorMoved` is.
+    ///
+    /// Call this from the event loop's `DeviceEvent` handler alongside
+    /// `handle_event`'s `WindowEvent` handling.
+    pub fn handle_device_event(&mut self, event: &DeviceEvent) {
+        if let DeviceEvent::MouseMotion { delta } = event {
+            self.raw_mouse_delta.0 += delta.0;
+            self.raw_mouse_delta.1 += delta.1;
+            self.mouse_motion_events
+                .send(MouseMotion { delta: *delta });
+        }
+    }
+
+    /// Drains pending events from a `gilrs::Gilrs` instance and feeds them
+    /// into this frame's input state.
+    ///
+    /// Call this once per frame (e.g. alongside `update()`) to keep gamepad
+    /// state in sync with the backend.
+    pub fn poll_gamepads(&mut self, gilrs: &mut gilrs::Gilrs) {
+        while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event() {
+            self.handle_gamepad_event(id, event);
+        }
+    }
+
+    /// Processes a single `gilrs` event for the given raw gamepad id
+    fn handle_gamepad_event(&mut self, raw_id: gilrs::GamepadId, event: gilrs::EventType) {
+        match event {
+            gilrs::EventType::Connected => {
+                self.gamepad_id_for(raw_id);
+            }
+            gilrs::EventType::Disconnected => {
+                if let Some(id) = self.gamepad_ids.remove(&raw_id) {
+                    self.gamepad_buttons_pressed.retain(|(gid, _)| *gid != id);
+                    self.gamepad_axes.retain(|(gid, _), _| *gid != id);
+                }
+            }
+            gilrs::EventType::ButtonPressed(button, _) => {
+                let id = self.gamepad_id_for(raw_id);
+                if !self.gamepad_buttons_pressed.contains(&(id, button)) {
+                    self.gamepad_buttons_just_pressed.insert((id, button));
+                }
+                self.gamepad_buttons_pressed.insert((id, button));
+            }
+            gilrs::EventType::ButtonReleased(button, _) => {
+                let id = self.gamepad_id_for(raw_id);
+                self.gamepad_buttons_pressed.remove(&(id, button));
+                self.gamepad_buttons_just_released.insert((id, button));
+            }
+            gilrs::EventType::AxisChanged(axis, value, _) => {
+                let id = self.gamepad_id_for(raw_id);
+                self.gamepad_axes.insert((id, axis), value);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the engine-assigned id for a raw backend id, assigning one if
+    /// this is the first event seen for it (e.g. a missed `Connected` event)
+    fn gamepad_id_for(&mut self, raw_id: gilrs::GamepadId) -> GamepadId {
+        *self.gamepad_ids.entry(raw_id).or_insert_with(|| {
+            let id = self.next_gamepad_id;
+            self.next_gamepad_id += 1;
+            id
+        })
     }
 
     /// Processes a window event and updates input state accordingly
@@ -69,10 +301,12 @@ impl InputState {
             }
             WindowEvent::CursorEntered { .. } => {
                 self.cursor_in_window = true;
+                self.cursor_entered_events.send(CursorEntered);
             }
             WindowEvent::CursorLeft { .. } => {
                 self.cursor_in_window = false;
                 self.mouse_position = None;
+                self.cursor_left_events.send(CursorLeft);
             }
             WindowEvent::MouseInput { button, state, .. } => {
                 self.handle_mouse_button(*button, *state);
@@ -92,10 +326,16 @@ impl InputState {
                     self.keys_just_pressed.insert(key_code);
                 }
                 self.keys_pressed.insert(key_code);
+                self.key_press_times
+                    .entry(key_code)
+                    .or_insert(self.elapsed_time);
+                self.key_pressed_events.send(KeyPressed { key: key_code });
             }
             ElementState::Released => {
                 self.keys_pressed.remove(&key_code);
                 self.keys_just_released.insert(key_code);
+                self.key_press_times.remove(&key_code);
+                self.key_released_events.send(KeyReleased { key: key_code });
             }
         }
     }
@@ -108,28 +348,65 @@ impl InputState {
                     self.mouse_buttons_just_pressed.insert(button);
                 }
                 self.mouse_buttons_pressed.insert(button);
+                self.mouse_button_press_times
+                    .insert(button, self.elapsed_time);
             }
             ElementState::Released => {
                 self.mouse_buttons_pressed.remove(&button);
                 self.mouse_buttons_just_released.insert(button);
+                self.mouse_button_press_times.remove(&button);
+                self.register_click(button);
             }
         }
+        self.mouse_button_events
+            .send(MouseButtonChanged { button, state });
+    }
+
+    /// Updates the multi-click counter for `button` on release
+    fn register_click(&mut self, button: MouseButton) {
+        /// Releases within this many seconds of each other count as the same click streak
+        const MAX_REPEAT_TIME: f32 = 0.1;
+        /// Releases further apart than this many pixels start a new click streak
+        const MAX_REPEAT_DISTANCE: f64 = 8.0;
+
+        let now = self.elapsed_time;
+        let position = self.mouse_position.unwrap_or((0.0, 0.0));
+
+        let is_repeat_click = match self.last_mouse_release.get(&button) {
+            Some((last_time, last_position)) => {
+                let dx = position.0 - last_position.0;
+                let dy = position.1 - last_position.1;
+                (now - last_time) <= MAX_REPEAT_TIME
+                    && (dx * dx + dy * dy).sqrt() <= MAX_REPEAT_DISTANCE
+            }
+            None => false,
+        };
+
+        let count = if is_repeat_click {
+            self.mouse_click_counts.get(&button).copied().unwrap_or(1) + 1
+        } else {
+            1
+        };
+        self.mouse_click_counts.insert(button, count);
+        self.last_mouse_release.insert(button, (now, position));
     }
 
     /// Handles mouse wheel events
     fn handle_mouse_wheel(&mut self, delta: winit::event::MouseScrollDelta) {
-        match delta {
-            winit::event::MouseScrollDelta::LineDelta(x, y) => {
-                self.mouse_wheel_delta.0 += x;
-                self.mouse_wheel_delta.1 += y;
-            }
+        let line_delta = match delta {
+            winit::event::MouseScrollDelta::LineDelta(x, y) => (x, y),
             winit::event::MouseScrollDelta::PixelDelta(pos) => {
                 // Convert pixel delta to line delta approximation
                 const PIXELS_PER_LINE: f64 = 50.0;
-                self.mouse_wheel_delta.0 += (pos.x / PIXELS_PER_LINE) as f32;
-                self.mouse_wheel_delta.1 += (pos.y / PIXELS_PER_LINE) as f32;
+                (
+                    (pos.x / PIXELS_PER_LINE) as f32,
+                    (pos.y / PIXELS_PER_LINE) as f32,
+                )
             }
-        }
+        };
+        self.mouse_wheel_delta.0 += line_delta.0;
+        self.mouse_wheel_delta.1 += line_delta.1;
+        self.mouse_wheel_events.send(MouseWheel { delta: line_delta });
     }
 
     /// Returns true if the key is currently being held down
@@ -180,6 +457,51 @@ impl InputState {
         self.mouse_wheel_delta
     }
 
+    /// Returns the unbounded relative mouse motion accumulated this frame.
+    ///
+    /// Unlike `mouse_delta()`, this is not derived from clamped screen
+    /// positions, so it keeps reporting movement while the cursor is grabbed
+    /// or pinned at a window edge. This is what first-person look controls
+    /// should use.
+    pub fn raw_mouse_delta(&self) -> (f64, f64) {
+        self.raw_mouse_delta
+    }
+
+    /// Iterates discrete key-press events buffered this frame
+    pub fn key_pressed_events(&self) -> impl Iterator<Item = &KeyPressed> {
+        self.key_pressed_events.iter()
+    }
+
+    /// Iterates discrete key-release events buffered this frame
+    pub fn key_released_events(&self) -> impl Iterator<Item = &KeyReleased> {
+        self.key_released_events.iter()
+    }
+
+    /// Iterates discrete mouse button events buffered this frame
+    pub fn mouse_button_events(&self) -> impl Iterator<Item = &MouseButtonChanged> {
+        self.mouse_button_events.iter()
+    }
+
+    /// Iterates discrete raw mouse motion events buffered this frame
+    pub fn mouse_motion_events(&self) -> impl Iterator<Item = &MouseMotion> {
+        self.mouse_motion_events.iter()
+    }
+
+    /// Iterates discrete mouse wheel events buffered this frame
+    pub fn mouse_wheel_events(&self) -> impl Iterator<Item = &MouseWheel> {
+        self.mouse_wheel_events.iter()
+    }
+
+    /// Iterates discrete cursor-entered events buffered this frame
+    pub fn cursor_entered_events(&self) -> impl Iterator<Item = &CursorEntered> {
+        self.cursor_entered_events.iter()
+    }
+
+    /// Iterates discrete cursor-left events buffered this frame
+    pub fn cursor_left_events(&self) -> impl Iterator<Item = &CursorLeft> {
+        self.cursor_left_events.iter()
+    }
+
     /// Returns true if the cursor is within the window bounds
     pub fn is_cursor_in_window(&self) -> bool {
         self.cursor_in_window
@@ -194,15 +516,327 @@ impl InputState {
     pub fn pressed_mouse_buttons(&self) -> &HashSet<MouseButton> {
         &self.mouse_buttons_pressed
     }
+
+    /// Iterates keys that were pressed this frame, for rebind-prompt capture
+    pub fn just_pressed_keys(&self) -> impl Iterator<Item = &KeyCode> {
+        self.keys_just_pressed.iter()
+    }
+
+    /// Iterates mouse buttons that were pressed this frame, for rebind-prompt capture
+    pub fn just_pressed_mouse_buttons(&self) -> impl Iterator<Item = &MouseButton> {
+        self.mouse_buttons_just_pressed.iter()
+    }
+
+    /// Iterates `(gamepad, button)` pairs pressed this frame, for rebind-prompt capture
+    pub fn just_pressed_gamepad_buttons(&self) -> impl Iterator<Item = &(GamepadId, gilrs::Button)> {
+        self.gamepad_buttons_just_pressed.iter()
+    }
+
+    /// Returns true if `key` should fire a repeat this frame: it has been
+    /// held for at least `initial_delay` seconds, and then fires again every
+    /// `interval` seconds thereafter. Useful for menu navigation and
+    /// text-like input.
+    pub fn is_key_repeating(&self, key: KeyCode, initial_delay: f32, interval: f32) -> bool {
+        let Some(&press_time) = self.key_press_times.get(&key) else {
+            return false;
+        };
+
+        let repeat_tick = |since_press: f32| -> i64 {
+            if since_press < initial_delay {
+                0
+            } else {
+                (((since_press - initial_delay) / interval).floor() as i64) + 1
+            }
+        };
+
+        let since_press_now = self.elapsed_time - press_time;
+        let since_press_prev = self.previous_elapsed_time - press_time;
+        repeat_tick(since_press_now) > repeat_tick(since_press_prev)
+    }
+
+    /// Returns the consecutive click count for `button` as of its most
+    /// recent release (2 for a double-click, 3 for a triple-click, etc.).
+    /// Resets to 1 once a release falls outside the multi-click window.
+    pub fn mouse_click_count(&self, button: MouseButton) -> u32 {
+        self.mouse_click_counts.get(&button).copied().unwrap_or(0)
+    }
+
+    /// Returns the currently held modifier keys
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers {
+            ctrl: self.keys_pressed.contains(&KeyCode::ControlLeft)
+                || self.keys_pressed.contains(&KeyCode::ControlRight),
+            shift: self.keys_pressed.contains(&KeyCode::ShiftLeft)
+                || self.keys_pressed.contains(&KeyCode::ShiftRight),
+            alt: self.keys_pressed.contains(&KeyCode::AltLeft)
+                || self.keys_pressed.contains(&KeyCode::AltRight),
+            super_key: self.keys_pressed.contains(&KeyCode::SuperLeft)
+                || self.keys_pressed.contains(&KeyCode::SuperRight),
+        }
+    }
+
+    /// Returns the ids of all currently connected gamepads
+    pub fn connected_gamepads(&self) -> impl Iterator<Item = GamepadId> + '_ {
+        self.gamepad_ids.values().copied()
+    }
+
+    /// Returns true if the given gamepad is currently connected
+    pub fn is_gamepad_connected(&self, gamepad: GamepadId) -> bool {
+        self.gamepad_ids.values().any(|id| *id == gamepad)
+    }
+
+    /// Returns true if the button is currently held on the given gamepad
+    pub fn is_gamepad_button_pressed(&self, gamepad: GamepadId, button: gilrs::Button) -> bool {
+        self.gamepad_buttons_pressed.contains(&(gamepad, button))
+    }
+
+    /// Returns true if the button was pressed this frame on the given gamepad
+    pub fn is_gamepad_button_just_pressed(
+        &self,
+        gamepad: GamepadId,
+        button: gilrs::Button,
+    ) -> bool {
+        self.gamepad_buttons_just_pressed
+            .contains(&(gamepad, button))
+    }
+
+    /// Returns true if the button was released this frame on the given gamepad
+    pub fn is_gamepad_button_just_released(
+        &self,
+        gamepad: GamepadId,
+        button: gilrs::Button,
+    ) -> bool {
+        self.gamepad_buttons_just_released
+            .contains(&(gamepad, button))
+    }
+
+    /// Returns the current value of an analog axis on the given gamepad, or
+    /// `0.0` if it has never reported a value
+    pub fn gamepad_axis_value(&self, gamepad: GamepadId, axis: gilrs::Axis) -> f32 {
+        self.gamepad_axes
+            .get(&(gamepad, axis))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns true if the button is held on any connected gamepad
+    fn is_gamepad_button_pressed_any(&self, button: gilrs::Button) -> bool {
+        self.gamepad_buttons_pressed
+            .iter()
+            .any(|(_, b)| *b == button)
+    }
+
+    /// Returns true if the button was pressed this frame on any connected gamepad
+    fn is_gamepad_button_just_pressed_any(&self, button: gilrs::Button) -> bool {
+        self.gamepad_buttons_just_pressed
+            .iter()
+            .any(|(_, b)| *b == button)
+    }
+
+    /// Returns the axis value with the largest magnitude across all connected
+    /// gamepads, or `0.0` if none are connected
+    fn gamepad_axis_value_any(&self, axis: gilrs::Axis) -> f32 {
+        self.gamepad_axes
+            .iter()
+            .filter(|((_, a), _)| *a == axis)
+            .map(|(_, value)| *value)
+            .fold(0.0, |best, value| {
+                if value.abs() > best.abs() {
+                    value
+                } else {
+                    best
+                }
+            })
+    }
+}
+
+/// Applies a simple axial deadzone, rescaling the remaining range to `[-1.0, 1.0]`
+fn apply_deadzone(value: f32, deadzone: f32) -> f32 {
+    let magnitude = value.abs();
+    if magnitude <= deadzone {
+        0.0
+    } else {
+        value.signum() * ((magnitude - deadzone) / (1.0 - deadzone)).min(1.0)
+    }
+}
+
+/// Applies a radial deadzone to a 2D stick value, preserving direction
+fn apply_deadzone_2d(value: Vec2, deadzone: f32) -> Vec2 {
+    let magnitude = value.length();
+    if magnitude <= deadzone {
+        Vec2::ZERO
+    } else {
+        value.normalize() * ((magnitude - deadzone) / (1.0 - deadzone)).min(1.0)
+    }
+}
+
+/// Which modifier keys are held down
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    pub super_key: bool,
+}
+
+impl Modifiers {
+    /// No modifiers held
+    pub const NONE: Modifiers = Modifiers {
+        ctrl: false,
+        shift: false,
+        alt: false,
+        super_key: false,
+    };
+
+    /// Just Ctrl
+    pub fn ctrl() -> Self {
+        Self {
+            ctrl: true,
+            ..Default::default()
+        }
+    }
+
+    /// Just Shift
+    pub fn shift() -> Self {
+        Self {
+            shift: true,
+            ..Default::default()
+        }
+    }
+
+    /// Just Alt
+    pub fn alt() -> Self {
+        Self {
+            alt: true,
+            ..Default::default()
+        }
+    }
+
+    /// Just Super (Windows/Command key)
+    pub fn super_key() -> Self {
+        Self {
+            super_key: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if every modifier set in `other` is also set in `self`
+    pub fn contains(&self, other: Modifiers) -> bool {
+        (!other.ctrl || self.ctrl)
+            && (!other.shift || self.shift)
+            && (!other.alt || self.alt)
+            && (!other.super_key || self.super_key)
+    }
+
+    /// Number of modifier keys that are set
+    pub fn count(&self) -> u32 {
+        self.ctrl as u32 + self.shift as u32 + self.alt as u32 + self.super_key as u32
+    }
+}
+
+impl std::ops::BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Modifiers) -> Modifiers {
+        Modifiers {
+            ctrl: self.ctrl || rhs.ctrl,
+            shift: self.shift || rhs.shift,
+            alt: self.alt || rhs.alt,
+            super_key: self.super_key || rhs.super_key,
+        }
+    }
+}
+
+/// A modifier-aware chord: a primary key that must be held along with a set
+/// of modifier keys, e.g. Ctrl+S
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Chord {
+    pub modifiers: Modifiers,
+    pub key: KeyCode,
+}
+
+impl Chord {
+    /// Creates a new chord from a modifier set and a primary key
+    pub fn new(modifiers: Modifiers, key: KeyCode) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Total number of inputs making up this chord (modifiers + primary key)
+    fn input_count(&self) -> u32 {
+        self.modifiers.count() + 1
+    }
+
+    /// Returns true if this chord's input set strictly contains `other`'s,
+    /// i.e. same primary key, a superset of modifiers, and at least one more
+    fn strictly_contains(&self, other: &Chord) -> bool {
+        self.key == other.key
+            && self.modifiers.contains(other.modifiers)
+            && self.input_count() > other.input_count()
+    }
+
+    fn is_active(&self, state: &InputState) -> bool {
+        state.is_key_pressed(self.key) && state.modifiers().contains(self.modifiers)
+    }
+
+    fn is_just_pressed(&self, state: &InputState) -> bool {
+        state.is_key_just_pressed(self.key) && state.modifiers().contains(self.modifiers)
+    }
+}
+
+/// A single input captured by [`InputMap::listen_for_next_input`], ready to
+/// be assigned to an action for live rebinding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapturedInput {
+    Key(KeyCode),
+    MouseButton(MouseButton),
+    GamepadButton(gilrs::Button),
+}
+
+/// One source feeding a named 1D axis action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum AxisSource {
+    /// A positive/negative key pair, yielding `-1.0`, `0.0`, or `1.0`
+    Keys {
+        positive: KeyCode,
+        negative: KeyCode,
+    },
+    /// A physical gamepad stick axis with a configurable deadzone
+    GamepadAxis { axis: gilrs::Axis, deadzone: f32 },
+}
+
+/// One source feeding a named 2D axis-pair action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum AxisPairSource {
+    /// Four keys driving the X and Y components independently
+    Keys {
+        positive_x: KeyCode,
+        negative_x: KeyCode,
+        positive_y: KeyCode,
+        negative_y: KeyCode,
+    },
+    /// A physical gamepad stick (two axes) with a configurable radial deadzone
+    GamepadStick {
+        x_axis: gilrs::Axis,
+        y_axis: gilrs::Axis,
+        deadzone: f32,
+    },
 }
 
 /// Action-based input mapping for game actions
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InputMap {
     /// Maps action names to their keyboard bindings
     keyboard_bindings: std::collections::HashMap<String, Vec<KeyCode>>,
     /// Maps action names to their mouse button bindings
     mouse_bindings: std::collections::HashMap<String, Vec<MouseButton>>,
+    /// Maps action names to their gamepad button bindings (any connected gamepad)
+    gamepad_bindings: std::collections::HashMap<String, Vec<gilrs::Button>>,
+    /// Maps axis names to their bound sources
+    axis_bindings: std::collections::HashMap<String, Vec<AxisSource>>,
+    /// Maps axis-pair names to their bound sources
+    axis_pair_bindings: std::collections::HashMap<String, Vec<AxisPairSource>>,
+    /// Maps action names to their chorded (modifier + key) bindings
+    chord_bindings: std::collections::HashMap<String, Vec<Chord>>,
 }
 
 impl InputMap {
@@ -227,6 +861,14 @@ impl InputMap {
             .push(button);
     }
 
+    /// Binds a gamepad button to an action, matching on any connected gamepad
+    pub fn bind_gamepad_button(&mut self, action: impl Into<String>, button: gilrs::Button) {
+        self.gamepad_bindings
+            .entry(action.into())
+            .or_default()
+            .push(button);
+    }
+
     /// Returns true if the action is currently active
     pub fn is_action_active(&self, action: &str, input_state: &InputState) -> bool {
         // Check keyboard bindings
@@ -246,6 +888,23 @@ impl InputMap {
             }
         }
 
+        // Check gamepad bindings
+        if let Some(buttons) = self.gamepad_bindings.get(action) {
+            if buttons
+                .iter()
+                .any(|btn| input_state.is_gamepad_button_pressed_any(*btn))
+            {
+                return true;
+            }
+        }
+
+        // Check chord bindings, applying clash resolution against other chords
+        if self.chord_bindings.contains_key(action)
+            && self.active_chord_actions(input_state).contains(action)
+        {
+            return true;
+        }
+
         false
     }
 
@@ -268,19 +927,261 @@ impl InputMap {
             }
         }
 
+        // Check gamepad bindings
+        if let Some(buttons) = self.gamepad_bindings.get(action) {
+            if buttons
+                .iter()
+                .any(|btn| input_state.is_gamepad_button_just_pressed_any(*btn))
+            {
+                return true;
+            }
+        }
+
+        // Check chord bindings, applying clash resolution against other chords
+        if let Some(chords) = self.chord_bindings.get(action) {
+            if chords.iter().any(|chord| chord.is_just_pressed(input_state))
+                && self.active_chord_actions(input_state).contains(action)
+            {
+                return true;
+            }
+        }
+
         false
     }
 
+    /// Binds a modifier-aware chord to an action, e.g. `Ctrl+S` distinct from `S`
+    pub fn bind_chord(&mut self, action: impl Into<String>, modifiers: Modifiers, key: KeyCode) {
+        self.chord_bindings
+            .entry(action.into())
+            .or_default()
+            .push(Chord::new(modifiers, key));
+    }
+
+    /// Computes the set of chord-bound actions that are active this frame,
+    /// after suppressing any action whose chord is a strict subset of
+    /// another active chord belonging to a different action
+    fn active_chord_actions(&self, input_state: &InputState) -> HashSet<String> {
+        let candidates: Vec<(&str, &Chord)> = self
+            .chord_bindings
+            .iter()
+            .flat_map(|(action, chords)| {
+                chords
+                    .iter()
+                    .filter(|chord| chord.is_active(input_state))
+                    .map(move |chord| (action.as_str(), chord))
+            })
+            .collect();
+
+        candidates
+            .iter()
+            .filter(|&&(action, chord)| {
+                !candidates
+                    .iter()
+                    .any(|&(other_action, other_chord)| {
+                        other_action != action && other_chord.strictly_contains(chord)
+                    })
+            })
+            .map(|&(action, _)| action.to_string())
+            .collect()
+    }
+
     /// Removes all bindings for an action
     pub fn unbind_action(&mut self, action: &str) {
         self.keyboard_bindings.remove(action);
         self.mouse_bindings.remove(action);
+        self.gamepad_bindings.remove(action);
+        self.chord_bindings.remove(action);
     }
 
     /// Clears all bindings
     pub fn clear_bindings(&mut self) {
         self.keyboard_bindings.clear();
         self.mouse_bindings.clear();
+        self.gamepad_bindings.clear();
+        self.chord_bindings.clear();
+        self.axis_bindings.clear();
+        self.axis_pair_bindings.clear();
+    }
+
+    /// Returns the keys currently bound to an action, for display in a
+    /// settings menu
+    pub fn bindings_for_action(&self, action: &str) -> &[KeyCode] {
+        self.keyboard_bindings
+            .get(action)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Replaces one key binding for an action with another, preserving its
+    /// position. Does nothing if `old` isn't currently bound to `action`.
+    pub fn rebind_key(&mut self, action: &str, old: KeyCode, new: KeyCode) {
+        if let Some(keys) = self.keyboard_bindings.get_mut(action) {
+            for key in keys.iter_mut() {
+                if *key == old {
+                    *key = new;
+                }
+            }
+        }
+    }
+
+    /// Looks for a key, mouse button, or gamepad button that was just
+    /// pressed this frame. Poll this every frame while a "press any input"
+    /// rebind prompt is open; it returns `Some` the first frame something is
+    /// pressed.
+    pub fn listen_for_next_input(input_state: &InputState) -> Option<CapturedInput> {
+        if let Some(&key) = input_state.just_pressed_keys().next() {
+            return Some(CapturedInput::Key(key));
+        }
+        if let Some(&button) = input_state.just_pressed_mouse_buttons().next() {
+            return Some(CapturedInput::MouseButton(button));
+        }
+        if let Some(&(_, button)) = input_state.just_pressed_gamepad_buttons().next() {
+            return Some(CapturedInput::GamepadButton(button));
+        }
+        None
+    }
+
+    /// Assigns a captured input (from [`InputMap::listen_for_next_input`]) to an action
+    pub fn assign_captured_input(&mut self, action: impl Into<String>, captured: CapturedInput) {
+        match captured {
+            CapturedInput::Key(key) => self.bind_key(action, key),
+            CapturedInput::MouseButton(button) => self.bind_mouse_button(action, button),
+            CapturedInput::GamepadButton(button) => self.bind_gamepad_button(action, button),
+        }
+    }
+
+    /// Binds a 1D axis to a positive/negative key pair, e.g. `D`/`A` for strafe.
+    /// The resulting value is `1.0` while only `positive_key` is held, `-1.0`
+    /// while only `negative_key` is held, and `0.0` otherwise.
+    pub fn bind_axis(&mut self, name: impl Into<String>, positive_key: KeyCode, negative_key: KeyCode) {
+        self.axis_bindings
+            .entry(name.into())
+            .or_default()
+            .push(AxisSource::Keys {
+                positive: positive_key,
+                negative: negative_key,
+            });
+    }
+
+    /// Binds a 1D axis to a physical gamepad stick axis, with a deadzone in
+    /// `[0.0, 1.0)` applied before the value is reported
+    pub fn bind_gamepad_axis(&mut self, name: impl Into<String>, axis: gilrs::Axis, deadzone: f32) {
+        self.axis_bindings
+            .entry(name.into())
+            .or_default()
+            .push(AxisSource::GamepadAxis { axis, deadzone });
+    }
+
+    /// Binds a 2D axis pair to four keys, e.g. WASD for movement
+    pub fn bind_axis_pair(
+        &mut self,
+        name: impl Into<String>,
+        positive_x: KeyCode,
+        negative_x: KeyCode,
+        positive_y: KeyCode,
+        negative_y: KeyCode,
+    ) {
+        self.axis_pair_bindings
+            .entry(name.into())
+            .or_default()
+            .push(AxisPairSource::Keys {
+                positive_x,
+                negative_x,
+                positive_y,
+                negative_y,
+            });
+    }
+
+    /// Binds a 2D axis pair to a physical gamepad stick, with a radial
+    /// deadzone in `[0.0, 1.0)` applied before the value is reported
+    pub fn bind_gamepad_axis_pair(
+        &mut self,
+        name: impl Into<String>,
+        x_axis: gilrs::Axis,
+        y_axis: gilrs::Axis,
+        deadzone: f32,
+    ) {
+        self.axis_pair_bindings
+            .entry(name.into())
+            .or_default()
+            .push(AxisPairSource::GamepadStick {
+                x_axis,
+                y_axis,
+                deadzone,
+            });
+    }
+
+    /// Returns the current value of a named 1D axis, in `[-1.0, 1.0]`.
+    ///
+    /// When multiple bound sources are active at once, the one with the
+    /// largest absolute value wins.
+    pub fn axis_value(&self, name: &str, input_state: &InputState) -> f32 {
+        let Some(sources) = self.axis_bindings.get(name) else {
+            return 0.0;
+        };
+
+        sources
+            .iter()
+            .map(|source| match source {
+                AxisSource::Keys { positive, negative } => {
+                    (input_state.is_key_pressed(*positive) as i32 as f32)
+                        - (input_state.is_key_pressed(*negative) as i32 as f32)
+                }
+                AxisSource::GamepadAxis { axis, deadzone } => {
+                    apply_deadzone(input_state.gamepad_axis_value_any(*axis), *deadzone)
+                }
+            })
+            .fold(0.0_f32, |best, value| {
+                if value.abs() > best.abs() {
+                    value
+                } else {
+                    best
+                }
+            })
+    }
+
+    /// Returns the current value of a named 2D axis pair.
+    ///
+    /// When multiple bound sources are active at once, the one with the
+    /// largest magnitude wins.
+    pub fn axis_pair(&self, name: &str, input_state: &InputState) -> Vec2 {
+        let Some(sources) = self.axis_pair_bindings.get(name) else {
+            return Vec2::ZERO;
+        };
+
+        sources
+            .iter()
+            .map(|source| match source {
+                AxisPairSource::Keys {
+                    positive_x,
+                    negative_x,
+                    positive_y,
+                    negative_y,
+                } => Vec2::new(
+                    (input_state.is_key_pressed(*positive_x) as i32 as f32)
+                        - (input_state.is_key_pressed(*negative_x) as i32 as f32),
+                    (input_state.is_key_pressed(*positive_y) as i32 as f32)
+                        - (input_state.is_key_pressed(*negative_y) as i32 as f32),
+                ),
+                AxisPairSource::GamepadStick {
+                    x_axis,
+                    y_axis,
+                    deadzone,
+                } => apply_deadzone_2d(
+                    Vec2::new(
+                        input_state.gamepad_axis_value_any(*x_axis),
+                        input_state.gamepad_axis_value_any(*y_axis),
+                    ),
+                    *deadzone,
+                ),
+            })
+            .fold(Vec2::ZERO, |best, value| {
+                if value.length() > best.length() {
+                    value
+                } else {
+                    best
+                }
+            })
     }
 }
 
@@ -299,7 +1200,7 @@ mod tests {
         assert!(input.is_key_just_pressed(KeyCode::KeyW));
 
         // Update clears "just" states
-        input.update();
+        input.update(1.0 / 60.0);
         assert!(input.is_key_pressed(KeyCode::KeyW));
         assert!(!input.is_key_just_pressed(KeyCode::KeyW));
 
@@ -309,7 +1210,7 @@ mod tests {
         assert!(input.is_key_just_released(KeyCode::KeyW));
 
         // Update clears "just" states
-        input.update();
+        input.update(1.0 / 60.0);
         assert!(!input.is_key_just_released(KeyCode::KeyW));
     }
 
@@ -338,11 +1239,218 @@ mod tests {
         assert!(input.is_mouse_button_pressed(MouseButton::Left));
         assert!(input.is_mouse_button_just_pressed(MouseButton::Left));
 
-        input.update();
+        input.update(1.0 / 60.0);
         assert!(!input.is_mouse_button_just_pressed(MouseButton::Left));
 
         input.handle_mouse_button(MouseButton::Left, ElementState::Released);
         assert!(!input.is_mouse_button_pressed(MouseButton::Left));
         assert!(input.is_mouse_button_just_released(MouseButton::Left));
     }
+
+    #[test]
+    fn test_no_gamepads_connected_by_default() {
+        let input = InputState::new();
+
+        assert_eq!(input.connected_gamepads().count(), 0);
+        assert!(!input.is_gamepad_connected(0));
+        assert_eq!(input.gamepad_axis_value(0, gilrs::Axis::LeftStickX), 0.0);
+    }
+
+    #[test]
+    fn test_gamepad_binding_inactive_without_connection() {
+        let mut input_map = InputMap::new();
+        let input_state = InputState::new();
+
+        input_map.bind_gamepad_button("jump", gilrs::Button::South);
+
+        assert!(!input_map.is_action_active("jump", &input_state));
+    }
+
+    #[test]
+    fn test_axis_value_from_key_pair() {
+        let mut input_map = InputMap::new();
+        let mut input_state = InputState::new();
+
+        input_map.bind_axis("move_x", KeyCode::KeyD, KeyCode::KeyA);
+        assert_eq!(input_map.axis_value("move_x", &input_state), 0.0);
+
+        input_state.handle_key(KeyCode::KeyD, ElementState::Pressed);
+        assert_eq!(input_map.axis_value("move_x", &input_state), 1.0);
+
+        input_state.handle_key(KeyCode::KeyD, ElementState::Released);
+        input_state.handle_key(KeyCode::KeyA, ElementState::Pressed);
+        assert_eq!(input_map.axis_value("move_x", &input_state), -1.0);
+    }
+
+    #[test]
+    fn test_axis_pair_from_wasd() {
+        let mut input_map = InputMap::new();
+        let mut input_state = InputState::new();
+
+        input_map.bind_axis_pair(
+            "movement",
+            KeyCode::KeyD,
+            KeyCode::KeyA,
+            KeyCode::KeyW,
+            KeyCode::KeyS,
+        );
+
+        input_state.handle_key(KeyCode::KeyW, ElementState::Pressed);
+        input_state.handle_key(KeyCode::KeyD, ElementState::Pressed);
+
+        assert_eq!(input_map.axis_pair("movement", &input_state), Vec2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_deadzone_zeroes_small_values() {
+        assert_eq!(apply_deadzone(0.05, 0.2), 0.0);
+        assert!(apply_deadzone(1.0, 0.2) > 0.9);
+    }
+
+    #[test]
+    fn test_chord_clash_resolution_suppresses_shorter_action() {
+        let mut input_map = InputMap::new();
+        let mut input_state = InputState::new();
+
+        input_map.bind_chord("save", Modifiers::NONE, KeyCode::KeyS);
+        input_map.bind_chord("save_as", Modifiers::ctrl(), KeyCode::KeyS);
+
+        input_state.handle_key(KeyCode::KeyS, ElementState::Pressed);
+        assert!(input_map.is_action_active("save", &input_state));
+        assert!(!input_map.is_action_active("save_as", &input_state));
+
+        input_state.handle_key(KeyCode::ControlLeft, ElementState::Pressed);
+        assert!(input_map.is_action_active("save_as", &input_state));
+        assert!(!input_map.is_action_active("save", &input_state));
+    }
+
+    #[test]
+    fn test_events_double_buffer_visible_after_update() {
+        let mut events = Events::new();
+        events.send(1);
+        events.send(2);
+        assert!(events.is_empty());
+
+        events.update();
+        assert_eq!(events.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_key_pressed_event_buffered_for_one_frame() {
+        let mut input = InputState::new();
+
+        input.handle_key(KeyCode::KeyW, ElementState::Pressed);
+        assert_eq!(input.key_pressed_events().count(), 0);
+
+        input.update(1.0 / 60.0);
+        let pressed: Vec<_> = input.key_pressed_events().map(|e| e.key).collect();
+        assert_eq!(pressed, vec![KeyCode::KeyW]);
+
+        input.update(1.0 / 60.0);
+        assert_eq!(input.key_pressed_events().count(), 0);
+    }
+
+    #[test]
+    fn test_raw_mouse_delta_accumulates_and_resets() {
+        let mut input = InputState::new();
+
+        input.handle_device_event(&DeviceEvent::MouseMotion { delta: (3.0, -2.0) });
+        input.handle_device_event(&DeviceEvent::MouseMotion { delta: (1.0, 1.0) });
+        assert_eq!(input.raw_mouse_delta(), (4.0, -1.0));
+
+        input.update(1.0 / 60.0);
+        assert_eq!(input.raw_mouse_delta(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_input_map_serde_round_trip() {
+        let mut input_map = InputMap::new();
+        input_map.bind_key("jump", KeyCode::Space);
+        input_map.bind_chord("save", Modifiers::ctrl(), KeyCode::KeyS);
+
+        let json = serde_json::to_string(&input_map).expect("serialize");
+        let restored: InputMap = serde_json::from_str(&json).expect("deserialize");
+
+        assert_eq!(restored.bindings_for_action("jump"), &[KeyCode::Space]);
+    }
+
+    #[test]
+    fn test_rebind_key() {
+        let mut input_map = InputMap::new();
+        input_map.bind_key("jump", KeyCode::Space);
+
+        input_map.rebind_key("jump", KeyCode::Space, KeyCode::KeyJ);
+
+        assert_eq!(input_map.bindings_for_action("jump"), &[KeyCode::KeyJ]);
+    }
+
+    #[test]
+    fn test_listen_for_next_input_captures_key() {
+        let mut input_state = InputState::new();
+        assert!(InputMap::listen_for_next_input(&input_state).is_none());
+
+        input_state.handle_key(KeyCode::KeyF, ElementState::Pressed);
+        assert_eq!(
+            InputMap::listen_for_next_input(&input_state),
+            Some(CapturedInput::Key(KeyCode::KeyF))
+        );
+    }
+
+    #[test]
+    fn test_unrelated_chords_both_remain_active() {
+        let mut input_map = InputMap::new();
+        let mut input_state = InputState::new();
+
+        input_map.bind_chord("save", Modifiers::ctrl(), KeyCode::KeyS);
+        input_map.bind_chord("open", Modifiers::ctrl(), KeyCode::KeyO);
+
+        input_state.handle_key(KeyCode::ControlLeft, ElementState::Pressed);
+        input_state.handle_key(KeyCode::KeyS, ElementState::Pressed);
+        input_state.handle_key(KeyCode::KeyO, ElementState::Pressed);
+
+        assert!(input_map.is_action_active("save", &input_state));
+        assert!(input_map.is_action_active("open", &input_state));
+    }
+
+    #[test]
+    fn test_key_repeat_fires_after_initial_delay_then_on_interval() {
+        let mut input = InputState::new();
+        input.handle_key(KeyCode::KeyW, ElementState::Pressed);
+
+        // Not yet past the initial delay
+        input.update(0.3);
+        assert!(!input.is_key_repeating(KeyCode::KeyW, 0.5, 0.1));
+
+        // Crosses the initial delay this frame
+        input.update(0.3);
+        assert!(input.is_key_repeating(KeyCode::KeyW, 0.5, 0.1));
+
+        // Doesn't fire again until a further `interval` has elapsed
+        input.update(0.05);
+        assert!(!input.is_key_repeating(KeyCode::KeyW, 0.5, 0.1));
+        input.update(0.1);
+        assert!(input.is_key_repeating(KeyCode::KeyW, 0.5, 0.1));
+    }
+
+    #[test]
+    fn test_double_click_detection() {
+        let mut input = InputState::new();
+
+        input.handle_mouse_button(MouseButton::Left, ElementState::Pressed);
+        input.handle_mouse_button(MouseButton::Left, ElementState::Released);
+        assert_eq!(input.mouse_click_count(MouseButton::Left), 1);
+
+        input.update(0.05);
+        input.handle_mouse_button(MouseButton::Left, ElementState::Pressed);
+        input.handle_mouse_button(MouseButton::Left, ElementState::Released);
+        assert_eq!(input.mouse_click_count(MouseButton::Left), 2);
+
+        input.update(0.2);
+        input.handle_mouse_button(MouseButton::Left, ElementState::Pressed);
+        input.handle_mouse_button(MouseButton::Left, ElementState::Released);
+        assert_eq!(input.mouse_click_count(MouseButton::Left), 1);
+    }
 }
@@ -4,9 +4,14 @@ pub mod window;
 
 pub use window::{create_event_loop, GameWindow};
 pub use winit;
+pub use gilrs;
+pub use glam;
 
 pub mod time;
-pub use time::{DeltaTime, FixedTimestep, FpsCounter};
+pub use time::{
+    AverageTimer, Clock, DeltaTime, FixedTimestep, FpsCounter, Interval, ManualClock, Profiler,
+    ScopeTimer, SystemClock,
+};
 
 pub mod input;
 pub use input::{InputMap, InputState};
@@ -1,23 +1,129 @@
-use std::time::Instant;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
 
-pub struct DeltaTime {
+/// A source of monotonic time, relative to some fixed epoch. Lets
+/// `DeltaTime` and `FpsCounter` be driven by something other than
+/// `std::time::Instant` (platform resolution/monotonicity guarantees vary),
+/// most importantly a `ManualClock` for deterministic tests.
+pub trait Clock {
+    /// Time elapsed since this clock's epoch
+    fn now(&self) -> Duration;
+}
+
+/// The default `Clock`, wrapping `std::time::Instant` so production code
+/// keeps riding whatever monotonic timer the platform provides
+/// (`QueryPerformanceCounter` on Windows, `CLOCK_MONOTONIC` elsewhere) at
+/// zero additional cost
+pub struct SystemClock {
+    epoch: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            epoch: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.epoch.elapsed()
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `Clock` whose time only advances when explicitly told to, so unit
+/// tests can feed exact frame deltas and reproduce spiral-of-death /
+/// interpolation edge cases without sleeping
+#[derive(Default)]
+pub struct ManualClock {
+    elapsed: Duration,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves this clock's `now()` forward by `delta`
+    pub fn advance(&mut self, delta: Duration) {
+        self.elapsed += delta;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Tracks wall-clock frame delta plus an independent, pausable simulation
+/// clock that accumulates scaled delta (`t += delta * time_scale`) and
+/// freezes while paused, so slow-motion/fast-forward/pause don't have to be
+/// reimplemented by every system that consumes time. Generic over `Clock`
+/// so it can be driven by a `ManualClock` in tests; defaults to the
+/// zero-cost `SystemClock` for production.
+pub struct DeltaTime<C: Clock = SystemClock> {
+    clock: C,
     current: f32,
-    previous: Instant,
+    previous: Duration,
+    /// Timestamp captured at the last `update()`, shared by every system in
+    /// the frame so they agree on "now" instead of each querying the clock
+    now: Duration,
+    simulation_time: f32,
+    time_scale: f32,
+    paused: bool,
 }
 
-impl DeltaTime {
+impl DeltaTime<SystemClock> {
     pub fn new() -> Self {
-        let now = Instant::now();
+        Self::with_clock(SystemClock::new())
+    }
+}
+
+impl Default for DeltaTime<SystemClock> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock> DeltaTime<C> {
+    /// Creates a `DeltaTime` driven by the given clock instead of the
+    /// default `SystemClock`
+    pub fn with_clock(clock: C) -> Self {
+        let now = clock.now();
         Self {
+            clock,
             current: 0.0,
             previous: now,
+            now,
+            simulation_time: 0.0,
+            time_scale: 1.0,
+            paused: false,
         }
     }
 
+    /// Advances wall time and, unless paused, the simulation clock. Wall
+    /// time always advances so a resumed frame after a long pause doesn't
+    /// see a huge delta spike.
     pub fn update(&mut self) {
-        let now = Instant::now();
-        self.current = now.duration_since(self.previous).as_secs_f32();
+        let now = self.clock.now();
+        let wall_delta = now.saturating_sub(self.previous).as_secs_f32();
         self.previous = now;
+        self.now = now;
+
+        if self.paused {
+            self.current = 0.0;
+        } else {
+            self.current = wall_delta * self.time_scale;
+            self.simulation_time += self.current;
+        }
     }
 
     pub fn delta(&self) -> f32 {
@@ -31,11 +137,42 @@ impl DeltaTime {
     pub fn milliseconds(&self) -> f32 {
         self.current * 1000.0
     }
-}
 
-impl Default for DeltaTime {
-    fn default() -> Self {
-        Self::new()
+    /// The clock reading captured at the last `update()`
+    pub fn now(&self) -> Duration {
+        self.now
+    }
+
+    /// Total simulation time accumulated since creation, excluding time
+    /// spent paused
+    pub fn elapsed(&self) -> f32 {
+        self.simulation_time
+    }
+
+    /// Freezes the simulation clock; `delta()` reports `0.0` until `resume()`
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Unfreezes the simulation clock
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Sets the rate the simulation clock advances relative to wall time,
+    /// for slow-motion (`< 1.0`) or fast-forward (`> 1.0`) effects
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale;
+    }
+
+    /// The clock driving this `DeltaTime`, for advancing a `ManualClock`
+    /// directly in tests
+    pub fn clock_mut(&mut self) -> &mut C {
+        &mut self.clock
     }
 }
 
@@ -43,19 +180,46 @@ pub struct FixedTimestep {
     accumulator: f32,
     timestep: f32,
     interpolation: f32,
+    /// Maximum number of whole ticks `update` will let accumulate before
+    /// clamping, to avoid the "spiral of death" after a long stall
+    max_substeps: u32,
+    /// Whether the most recent `update` had to clamp the accumulator
+    clamped_last_update: bool,
 }
 
 impl FixedTimestep {
+    /// Default cap on accumulated whole ticks before excess time is dropped
+    pub const DEFAULT_MAX_SUBSTEPS: u32 = 5;
+
     pub fn new(fixed_delta_time: f32) -> Self {
         Self {
             accumulator: 0.0,
             timestep: fixed_delta_time,
             interpolation: 0.0,
+            max_substeps: Self::DEFAULT_MAX_SUBSTEPS,
+            clamped_last_update: false,
         }
     }
 
+    /// Sets the cap on accumulated whole ticks; once `accumulator` exceeds
+    /// `max_substeps * timestep`, `update` drops the excess instead of
+    /// letting the caller's tick loop run unbounded
+    pub fn with_max_substeps(mut self, max_substeps: u32) -> Self {
+        self.max_substeps = max_substeps;
+        self
+    }
+
     pub fn update(&mut self, delta_time: f32) {
         self.accumulator += delta_time;
+
+        let max_accumulated = self.max_substeps as f32 * self.timestep;
+        if self.accumulator > max_accumulated {
+            self.accumulator = max_accumulated;
+            self.clamped_last_update = true;
+        } else {
+            self.clamped_last_update = false;
+        }
+
         self.interpolation = self.accumulator / self.timestep;
     }
 
@@ -80,40 +244,154 @@ impl FixedTimestep {
     pub fn accumulator(&self) -> f32 {
         self.accumulator
     }
+
+    /// Whether the most recent `update` had to clamp away accumulated time
+    /// that exceeded `max_substeps * timestep`, so callers can detect and
+    /// report the lost time
+    pub fn clamped_last_update(&self) -> bool {
+        self.clamped_last_update
+    }
+
+    /// How many whole ticks are currently pending, for draining the
+    /// accumulator with a bounded `for` loop instead of an open `while`
+    pub fn tick_count(&self) -> u32 {
+        (self.accumulator / self.timestep) as u32
+    }
 }
 
-pub struct FpsCounter {
-    frame_times: Vec<f32>,
-    max_samples: usize,
+/// Fixed-capacity ring buffer of recent frame times, driving `FpsCounter`'s
+/// sample window without the `Vec::remove(0)` shifting a naive queue would do
+struct FrameTimeRing {
+    samples: Vec<f32>,
+    write_index: usize,
+    filled: usize,
+}
+
+impl FrameTimeRing {
+    fn new(capacity: usize) -> Self {
+        Self {
+            samples: vec![0.0; capacity.max(1)],
+            write_index: 0,
+            filled: 0,
+        }
+    }
+
+    fn push(&mut self, value: f32) {
+        let capacity = self.samples.len();
+        self.samples[self.write_index] = value;
+        self.write_index = (self.write_index + 1) % capacity;
+        self.filled = (self.filled + 1).min(capacity);
+    }
+
+    fn live(&self) -> &[f32] {
+        &self.samples[..self.filled]
+    }
+
+    fn clear(&mut self) {
+        self.write_index = 0;
+        self.filled = 0;
+    }
+}
+
+/// A periodic timer that answers "has at least one full period elapsed?"
+/// and "how many whole periods fit?", advancing its internal mark by
+/// whole periods rather than resetting to `now` so it self-corrects
+/// against drift instead of losing the remainder each tick. Shared by
+/// `FpsCounter`'s refresh logic and usable directly for other periodic
+/// samplers (stats dumps, autosave, network sends). Marks are plain
+/// `Duration`s since some epoch rather than `Instant`s, so it can be driven
+/// by any `Clock`, including a `ManualClock` in tests.
+pub struct Interval {
+    period: Duration,
+    mark: Duration,
+}
+
+impl Interval {
+    /// Creates an interval of `period`, marked from `Duration::ZERO`
+    pub fn new(period: Duration) -> Self {
+        Self::new_at(period, Duration::ZERO)
+    }
+
+    /// Creates an interval of `period`, marked from `start` instead of zero
+    pub fn new_at(period: Duration, start: Duration) -> Self {
+        Self { period, mark: start }
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Re-marks the interval from `now`, discarding any pending periods
+    pub fn reset(&mut self, now: Duration) {
+        self.mark = now;
+    }
+
+    /// Returns `Some(n)` when at least one full period has elapsed since
+    /// the last mark, where `n` is the number of whole periods consumed,
+    /// and advances the mark by `n * period`. Returns `None` otherwise.
+    pub fn tick(&mut self, now: Duration) -> Option<u32> {
+        if self.period.is_zero() {
+            self.mark = now;
+            return Some(1);
+        }
+
+        let elapsed = now.saturating_sub(self.mark);
+        if elapsed < self.period {
+            return None;
+        }
+
+        let periods = (elapsed.as_nanos() / self.period.as_nanos()) as u32;
+        self.mark += self.period * periods;
+        Some(periods)
+    }
+}
+
+pub struct FpsCounter<C: Clock = SystemClock> {
+    clock: C,
+    frame_times: FrameTimeRing,
     current_fps: f32,
     frame_count: u32,
-    last_update: Instant,
-    update_interval: f32,
+    refresh_interval: Interval,
 }
 
-impl FpsCounter {
+impl FpsCounter<SystemClock> {
     pub fn new(max_samples: usize, update_interval: f32) -> Self {
+        Self::with_interval(max_samples, Interval::new(Duration::from_secs_f32(update_interval)))
+    }
+
+    /// Creates an `FpsCounter` that refreshes `fps()` on `refresh_interval`,
+    /// for callers that already have a drift-free `Interval` to share
+    pub fn with_interval(max_samples: usize, refresh_interval: Interval) -> Self {
+        Self::with_clock_and_interval(SystemClock::new(), max_samples, refresh_interval)
+    }
+}
+
+impl Default for FpsCounter<SystemClock> {
+    fn default() -> Self {
+        Self::new(60, 0.5)
+    }
+}
+
+impl<C: Clock> FpsCounter<C> {
+    /// Creates an `FpsCounter` driven by `clock` instead of the default
+    /// `SystemClock`, for deterministic tests
+    pub fn with_clock_and_interval(clock: C, max_samples: usize, refresh_interval: Interval) -> Self {
         Self {
-            frame_times: Vec::with_capacity(max_samples),
-            max_samples,
+            clock,
+            frame_times: FrameTimeRing::new(max_samples),
             current_fps: 0.0,
             frame_count: 0,
-            last_update: Instant::now(),
-            update_interval,
+            refresh_interval,
         }
     }
 
     pub fn update(&mut self, delta_time: f32) {
         self.frame_times.push(delta_time);
-        if self.frame_times.len() > self.max_samples {
-            self.frame_times.remove(0);
-        }
 
         self.frame_count += 1;
-        let elapsed = self.last_update.elapsed().as_secs_f32();
-        if elapsed >= self.update_interval {
-            let avg_frame_time = if !self.frame_times.is_empty() {
-                self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32
+        if self.refresh_interval.tick(self.clock.now()).is_some() {
+            let avg_frame_time = if !self.frame_times.live().is_empty() {
+                self.average_frame_time()
             } else {
                 delta_time
             };
@@ -122,7 +400,6 @@ impl FpsCounter {
             } else {
                 0.0
             };
-            self.last_update = Instant::now();
         }
     }
 
@@ -135,8 +412,64 @@ impl FpsCounter {
     }
 
     pub fn average_frame_time(&self) -> f32 {
-        if !self.frame_times.is_empty() {
-            self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32
+        let samples = self.frame_times.live();
+        if !samples.is_empty() {
+            samples.iter().sum::<f32>() / samples.len() as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// Fastest frame (lowest frame time) in the current sample window
+    pub fn min_frame_time(&self) -> f32 {
+        let samples = self.frame_times.live();
+        if samples.is_empty() {
+            return 0.0;
+        }
+        samples.iter().copied().fold(f32::INFINITY, f32::min)
+    }
+
+    /// Slowest frame (highest frame time) in the current sample window
+    pub fn max_frame_time(&self) -> f32 {
+        let samples = self.frame_times.live();
+        if samples.is_empty() {
+            return 0.0;
+        }
+        samples.iter().copied().fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Frame time at the `p`-th percentile (`0.0..=100.0`) of the current
+    /// sample window, by copying the live samples into a scratch buffer and
+    /// selecting the p-th element
+    pub fn percentile(&self, p: f32) -> f32 {
+        let samples = self.frame_times.live();
+        if samples.is_empty() {
+            return 0.0;
+        }
+
+        let mut scratch = samples.to_vec();
+        scratch.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let p = p.clamp(0.0, 100.0);
+        let index = ((p / 100.0) * (scratch.len() - 1) as f32).round() as usize;
+        scratch[index]
+    }
+
+    /// The mean frame time of the worst 1% of frames in the current sample
+    /// window, expressed as FPS, which surfaces stutters that average FPS hides
+    pub fn one_percent_low_fps(&self) -> f32 {
+        let samples = self.frame_times.live();
+        if samples.is_empty() {
+            return 0.0;
+        }
+
+        let mut scratch = samples.to_vec();
+        scratch.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+        let worst_count = (scratch.len() / 100).max(1);
+        let mean = scratch[..worst_count].iter().sum::<f32>() / worst_count as f32;
+        if mean > 0.0 {
+            1.0 / mean
         } else {
             0.0
         }
@@ -146,12 +479,418 @@ impl FpsCounter {
         self.frame_times.clear();
         self.current_fps = 0.0;
         self.frame_count = 0;
-        self.last_update = Instant::now();
+        self.refresh_interval.reset(self.clock.now());
+    }
+
+    /// The clock driving this `FpsCounter`, for advancing a `ManualClock`
+    /// directly in tests
+    pub fn clock_mut(&mut self) -> &mut C {
+        &mut self.clock
     }
 }
 
-impl Default for FpsCounter {
+/// Running high/low/average/count over a stream of recorded durations,
+/// accumulating `sum`/`count` rather than the lossy `(avg + x) / 2`
+/// recurrence so long sessions report a true mean
+#[derive(Clone, Copy, Debug)]
+pub struct AverageTimer {
+    high_nanos: u64,
+    low_nanos: u64,
+    sum_nanos: u64,
+    events: u64,
+}
+
+impl AverageTimer {
+    pub fn new() -> Self {
+        Self {
+            high_nanos: 0,
+            low_nanos: u64::MAX,
+            sum_nanos: 0,
+            events: 0,
+        }
+    }
+
+    fn record(&mut self, duration: Duration) {
+        let nanos = duration.as_nanos() as u64;
+        self.high_nanos = self.high_nanos.max(nanos);
+        self.low_nanos = self.low_nanos.min(nanos);
+        self.sum_nanos += nanos;
+        self.events += 1;
+    }
+
+    /// Longest recorded duration
+    pub fn high(&self) -> Duration {
+        Duration::from_nanos(self.high_nanos)
+    }
+
+    /// Shortest recorded duration
+    pub fn low(&self) -> Duration {
+        if self.events == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_nanos(self.low_nanos)
+        }
+    }
+
+    /// True mean over all recorded durations
+    pub fn average(&self) -> Duration {
+        if self.events == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_nanos(self.sum_nanos / self.events)
+        }
+    }
+
+    /// Number of durations recorded
+    pub fn events(&self) -> u64 {
+        self.events
+    }
+}
+
+impl Default for AverageTimer {
     fn default() -> Self {
-        Self::new(60, 0.5)
+        Self::new()
+    }
+}
+
+/// Named per-section timing, so a frame budget can be decomposed into where
+/// the time actually went (physics, render, input, ...) without pulling in
+/// a separate profiling crate
+pub struct Profiler {
+    sections: BTreeMap<String, AverageTimer>,
+    active: BTreeMap<String, Instant>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self {
+            sections: BTreeMap::new(),
+            active: BTreeMap::new(),
+        }
+    }
+
+    /// Starts timing the named section; pair with a matching `end(name)`
+    pub fn begin(&mut self, name: &str) {
+        self.active.insert(name.to_string(), Instant::now());
+    }
+
+    /// Stops timing the named section and records its elapsed time. No-op
+    /// if there was no matching `begin(name)`
+    pub fn end(&mut self, name: &str) {
+        if let Some(start) = self.active.remove(name) {
+            self.sections
+                .entry(name.to_string())
+                .or_insert_with(AverageTimer::new)
+                .record(start.elapsed());
+        }
+    }
+
+    /// Returns an RAII guard that records elapsed time into the named
+    /// section when dropped, for timing a scope without matching
+    /// `begin`/`end` calls by hand
+    pub fn scope(&mut self, name: &str) -> ScopeTimer<'_> {
+        ScopeTimer {
+            profiler: self,
+            name: name.to_string(),
+            start: Instant::now(),
+        }
+    }
+
+    /// The recorded statistics for a named section, if any events have
+    /// been recorded for it
+    pub fn section(&self, name: &str) -> Option<&AverageTimer> {
+        self.sections.get(name)
+    }
+
+    /// Formats every recorded section, e.g. `Decode: H:1.2ms A:900µs L:500µs over 42 events`
+    pub fn report(&self) -> String {
+        self.sections
+            .iter()
+            .map(|(name, timer)| {
+                format!(
+                    "{name}: H:{:?} A:{:?} L:{:?} over {} events",
+                    timer.high(),
+                    timer.average(),
+                    timer.low(),
+                    timer.events()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Clears all recorded sections and any in-flight `begin` calls
+    pub fn reset(&mut self) {
+        self.sections.clear();
+        self.active.clear();
+    }
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// RAII guard returned by `Profiler::scope` that records elapsed time into
+/// its section on drop
+pub struct ScopeTimer<'a> {
+    profiler: &'a mut Profiler,
+    name: String,
+    start: Instant,
+}
+
+impl Drop for ScopeTimer<'_> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        self.profiler
+            .sections
+            .entry(self.name.clone())
+            .or_insert_with(AverageTimer::new)
+            .record(elapsed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    #[test]
+    fn test_delta_time_pause_freezes_delta_but_not_wall_time() {
+        let mut time = DeltaTime::new();
+        time.update();
+        time.pause();
+        let before = time.now();
+
+        sleep(Duration::from_millis(5));
+        time.update();
+
+        assert_eq!(time.delta(), 0.0);
+        assert!(time.is_paused());
+        assert!(time.now() > before);
+    }
+
+    #[test]
+    fn test_delta_time_resume_does_not_spike_after_pause() {
+        let mut time = DeltaTime::new();
+        time.pause();
+        sleep(Duration::from_millis(20));
+        time.update();
+        assert_eq!(time.delta(), 0.0);
+
+        time.resume();
+        time.update();
+        // Only wall time since the last `update()` (not since `pause()`)
+        // should count once resumed
+        assert!(time.delta() < 0.02);
+    }
+
+    #[test]
+    fn test_delta_time_scale_speeds_up_elapsed() {
+        let mut unscaled = DeltaTime::new();
+        let mut doubled = DeltaTime::new();
+        doubled.set_time_scale(2.0);
+
+        unscaled.update();
+        doubled.update();
+        sleep(Duration::from_millis(5));
+        unscaled.update();
+        doubled.update();
+
+        assert!(doubled.elapsed() > unscaled.elapsed());
+    }
+
+    #[test]
+    fn test_delta_time_with_manual_clock_is_deterministic() {
+        let mut time = DeltaTime::with_clock(ManualClock::new());
+        time.update();
+
+        time.clock_mut().advance(Duration::from_millis(16));
+        time.update();
+
+        assert!((time.delta() - 0.016).abs() < 1e-6);
+        assert!((time.elapsed() - 0.016).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_delta_time_with_manual_clock_pause_reports_zero_delta() {
+        let mut time = DeltaTime::with_clock(ManualClock::new());
+        time.update();
+        time.pause();
+
+        time.clock_mut().advance(Duration::from_millis(500));
+        time.update();
+
+        assert_eq!(time.delta(), 0.0);
+    }
+
+    #[test]
+    fn test_interval_ticks_once_per_elapsed_period() {
+        let mut interval = Interval::new(Duration::from_millis(10));
+
+        assert_eq!(interval.tick(Duration::from_millis(5)), None);
+        assert_eq!(interval.tick(Duration::from_millis(10)), Some(1));
+    }
+
+    #[test]
+    fn test_interval_self_corrects_against_drift() {
+        let mut interval = Interval::new(Duration::from_millis(10));
+
+        // Three and a half periods elapsed in one big jump
+        assert_eq!(interval.tick(Duration::from_millis(35)), Some(3));
+
+        // The mark advanced by exactly 3 periods (not reset to 35ms), so
+        // the leftover 5ms carries forward instead of being dropped
+        assert_eq!(interval.tick(Duration::from_millis(40)), Some(1));
+    }
+
+    #[test]
+    fn test_fps_counter_with_interval_uses_shared_interval() {
+        let interval = Interval::new(Duration::from_secs_f32(0.0));
+        let mut fps = FpsCounter::with_interval(10, interval);
+        fps.update(1.0 / 60.0);
+
+        assert!(fps.fps() > 0.0);
+    }
+
+    #[test]
+    fn test_fps_counter_with_manual_clock_refreshes_on_exact_periods() {
+        let interval = Interval::new(Duration::from_millis(500));
+        let mut fps = FpsCounter::with_clock_and_interval(ManualClock::new(), 10, interval);
+
+        fps.update(1.0 / 60.0);
+        assert_eq!(fps.fps(), 0.0);
+
+        fps.clock_mut().advance(Duration::from_millis(500));
+        fps.update(1.0 / 60.0);
+        assert!(fps.fps() > 0.0);
+    }
+
+    #[test]
+    fn test_profiler_begin_end_records_section() {
+        let mut profiler = Profiler::new();
+        profiler.begin("physics");
+        sleep(Duration::from_millis(2));
+        profiler.end("physics");
+
+        let stats = profiler.section("physics").expect("section recorded");
+        assert_eq!(stats.events(), 1);
+        assert!(stats.average() >= Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_profiler_scope_timer_records_on_drop() {
+        let mut profiler = Profiler::new();
+        {
+            let _scope = profiler.scope("render");
+            sleep(Duration::from_millis(2));
+        }
+
+        let stats = profiler.section("render").expect("section recorded");
+        assert_eq!(stats.events(), 1);
+    }
+
+    #[test]
+    fn test_average_timer_tracks_true_mean_not_lossy_recurrence() {
+        let mut timer = AverageTimer::new();
+        timer.record(Duration::from_millis(10));
+        timer.record(Duration::from_millis(20));
+        timer.record(Duration::from_millis(30));
+
+        assert_eq!(timer.average(), Duration::from_millis(20));
+        assert_eq!(timer.high(), Duration::from_millis(30));
+        assert_eq!(timer.low(), Duration::from_millis(10));
+        assert_eq!(timer.events(), 3);
+    }
+
+    #[test]
+    fn test_profiler_reset_clears_sections() {
+        let mut profiler = Profiler::new();
+        profiler.begin("input");
+        profiler.end("input");
+        profiler.reset();
+
+        assert!(profiler.section("input").is_none());
+        assert!(profiler.report().is_empty());
+    }
+
+    #[test]
+    fn test_fps_counter_ring_buffer_wraps_without_shifting_window() {
+        let mut fps = FpsCounter::new(3, 0.0);
+        for frame_time in [0.1, 0.2, 0.3, 0.4] {
+            fps.update(frame_time);
+        }
+
+        // Oldest sample (0.1) should have been overwritten, not shifted out
+        // of a smaller window
+        let expected_average = (0.2 + 0.3 + 0.4) / 3.0;
+        assert!((fps.average_frame_time() - expected_average).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fps_counter_min_max_frame_time() {
+        let mut fps = FpsCounter::new(10, 0.0);
+        for frame_time in [0.1, 0.05, 0.2] {
+            fps.update(frame_time);
+        }
+
+        assert!((fps.min_frame_time() - 0.05).abs() < 1e-6);
+        assert!((fps.max_frame_time() - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fps_counter_percentile_of_uniform_samples() {
+        let mut fps = FpsCounter::new(100, 0.0);
+        for i in 0..100 {
+            fps.update(i as f32 / 1000.0);
+        }
+
+        assert!((fps.percentile(0.0) - 0.0).abs() < 1e-6);
+        assert!((fps.percentile(100.0) - 0.099).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fps_counter_one_percent_low_reflects_worst_frames() {
+        let mut fps = FpsCounter::new(100, 0.0);
+        for _ in 0..99 {
+            fps.update(1.0 / 144.0);
+        }
+        fps.update(1.0); // one big stutter frame
+
+        // The stutter should drag the 1% low far below the smooth average
+        assert!(fps.one_percent_low_fps() < fps.average_frame_time().recip());
+    }
+
+    #[test]
+    fn test_fixed_timestep_clamps_after_long_stall() {
+        let mut fixed = FixedTimestep::new(1.0 / 60.0).with_max_substeps(5);
+
+        fixed.update(10.0);
+
+        assert!(fixed.clamped_last_update());
+        assert_eq!(fixed.tick_count(), 5);
+    }
+
+    #[test]
+    fn test_fixed_timestep_does_not_clamp_normal_frame() {
+        let mut fixed = FixedTimestep::new(1.0 / 60.0).with_max_substeps(5);
+
+        fixed.update(1.0 / 60.0);
+
+        assert!(!fixed.clamped_last_update());
+        assert_eq!(fixed.tick_count(), 1);
+    }
+
+    #[test]
+    fn test_fixed_timestep_tick_count_drains_with_consume_tick() {
+        let mut fixed = FixedTimestep::new(1.0 / 60.0).with_max_substeps(10);
+        fixed.update(3.0 / 60.0);
+
+        assert_eq!(fixed.tick_count(), 3);
+        for _ in 0..fixed.tick_count() {
+            fixed.consume_tick();
+        }
+        assert_eq!(fixed.tick_count(), 0);
     }
 }